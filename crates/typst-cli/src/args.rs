@@ -304,6 +304,11 @@ pub struct CompileArgs {
     /// pages. Use `{p}` for page numbers, `{0p}` for zero padded page numbers
     /// and `{t}` for page count. For example, `page-{0p}-of-{t}.png` creates
     /// `page-01-of-10.png`, `page-02-of-10.png`, and so on.
+    ///
+    /// When compiling with `--records`, a record number template must be
+    /// present. Use `{r}` for record numbers and `{0r}` for zero padded record
+    /// numbers. For example, `letter-{0r}.pdf` creates `letter-01.pdf`,
+    /// `letter-02.pdf`, and so on.
     #[clap(
          required_if_eq("input", "-"),
          value_parser = output_value_parser(),
@@ -311,6 +316,17 @@ pub struct CompileArgs {
      )]
     pub output: Option<Output>,
 
+    /// Path to a JSON file containing an array of records. The document is
+    /// compiled once per record, with the record's fields available through
+    /// `sys.inputs` in addition to the ones given with `--input`.
+    ///
+    /// Each record must be an object. String values are passed as is, while
+    /// other values are passed in their JSON representation. Files, fonts,
+    /// and packages are loaded only once for all records. Diagnostics are
+    /// preceded by the number of the record they belong to.
+    #[arg(long = "records", value_name = "JSON", value_hint = ValueHint::FilePath)]
+    pub records: Option<PathBuf>,
+
     /// The format of the output file, inferred from the extension by default.
     #[arg(long = "format", short = 'f')]
     pub format: Option<OutputFormat>,
@@ -352,10 +368,32 @@ pub struct CompileArgs {
     #[arg(long = "no-pdf-tags")]
     pub no_pdf_tags: bool,
 
+    /// Downsamples raster images in PDF export whose resolution at their
+    /// placed size exceeds this many pixels per inch.
+    #[arg(long = "pdf-image-max-ppi", value_name = "PPI")]
+    pub pdf_image_max_ppi: Option<f64>,
+
+    /// Re-encodes opaque raster images in PDF export as JPEG with the given
+    /// quality, from 1 (smallest) to 100 (best).
+    #[arg(
+        long = "pdf-image-quality",
+        value_name = "QUALITY",
+        value_parser = clap::value_parser!(u8).range(1..=100),
+    )]
+    pub pdf_image_quality: Option<u8>,
+
     /// The PPI (pixels per inch) to use for PNG export.
     #[arg(long = "ppi", default_value_t = 144.0)]
     pub ppi: f64,
 
+    /// Draws frame boundaries (blue) and baselines (red) on top of the content
+    /// in PNG and SVG export to help with understanding layout decisions.
+    ///
+    /// Region splits and layout constraints are not recorded in the finished
+    /// document and thus not shown. Has no effect on other formats.
+    #[arg(long = "debug-overlay")]
+    pub debug_overlay: bool,
+
     /// File path to which a Makefile with the current compilation's
     /// dependencies will be written.
     #[clap(long = "make-deps", value_name = "PATH", hide = true)]
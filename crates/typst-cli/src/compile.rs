@@ -1,4 +1,5 @@
 use std::ffi::OsStr;
+use std::io::Write;
 use std::path::Path;
 
 use chrono::{DateTime, Datelike, Timelike, Utc};
@@ -9,9 +10,10 @@ use typst::diag::{
     At, HintedStrResult, HintedString, SourceDiagnostic, SourceResult, StrResult, Warned,
     bail,
 };
-use typst::foundations::{Datetime, Smart};
-use typst::layout::PageRanges;
+use typst::foundations::{Datetime, Dict, IntoValue, Smart};
+use typst::layout::{Abs, Frame, FrameItem, GroupItem, PageRanges, Point, Transform};
 use typst::syntax::Span;
+use typst::visualize::{Color, FixedStroke, Geometry};
 use typst_bundle::{Bundle, BundleOptions, VirtualFs};
 use typst_html::{HtmlDocument, HtmlOptions};
 use typst_kit::diagnostics::DiagnosticWorld;
@@ -44,15 +46,85 @@ pub fn compile(command: &'static CompileCommand) -> HintedStrResult<()> {
         &command.args.process,
     )
     .map_err(|err| eco_format!("{err}"))?;
+
+    if let Some(path) = &command.args.records {
+        let records = load_records(path)?;
+        return timer
+            .record(&mut world, |world| compile_records(world, &mut config, &records))?;
+    }
+
     timer.record(&mut world, |world| compile_once(world, &mut config))?
 }
 
+/// Compile once per record, exposing the record's fields through
+/// `sys.inputs`.
+///
+/// The world is reused across records, so files, fonts, and packages are only
+/// loaded once.
+fn compile_records(
+    world: &mut SystemWorld,
+    config: &mut CompileConfig,
+    records: &[Dict],
+) -> HintedStrResult<()> {
+    let Output::Path(template) = config.output.clone() else {
+        unreachable!(
+            "output must be a path when compiling records, as guarded by the config"
+        );
+    };
+    let template = template.to_str().ok_or("output path must be valid UTF-8")?;
+
+    for (i, record) in records.iter().enumerate() {
+        world.set_extra_inputs(record.clone());
+        world.reset();
+        config.record = Some((i + 1, records.len()));
+        config.output = Output::Path(
+            output_template::format_record(template, i + 1, records.len()).into(),
+        );
+        compile_once(world, config)?;
+
+        // Each record gets a fresh library, so results that depend on it are
+        // not reused by later records. Evict them so that the cache does not
+        // grow with every record.
+        comemo::evict(10);
+    }
+
+    Ok(())
+}
+
+/// Loads the records for batch compilation from a JSON file.
+fn load_records(path: &Path) -> StrResult<Vec<Dict>> {
+    let data = std::fs::read(path)
+        .map_err(|err| eco_format!("failed to read records ({err})"))?;
+    let records: Vec<serde_json::Map<String, serde_json::Value>> =
+        serde_json::from_slice(&data)
+            .map_err(|err| eco_format!("failed to parse records ({err})"))?;
+
+    Ok(records
+        .into_iter()
+        .map(|record| {
+            record
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        serde_json::Value::String(string) => string,
+                        other => other.to_string(),
+                    };
+                    (key.into(), value.into_value())
+                })
+                .collect()
+        })
+        .collect())
+}
+
 /// A preprocessed `CompileCommand`.
 pub struct CompileConfig {
     /// Static warnings to emit after compilation.
     pub warnings: Vec<HintedString>,
     /// Whether we are watching.
     pub watching: bool,
+    /// The number of the record being compiled and the total number of
+    /// records, when compiling with `--records`.
+    pub record: Option<(usize, usize)>,
     /// Path to input Typst file or stdin.
     pub input: Input,
     /// Path to output file (PDF, PNG, SVG, or HTML).
@@ -74,12 +146,18 @@ pub struct CompileConfig {
     pub pdf_standards: PdfStandards,
     /// Whether to write PDF (accessibility) tags.
     pub tagged: bool,
+    /// The resolution above which raster images are downsampled in PDF export.
+    pub pdf_image_max_ppi: Option<f64>,
+    /// The JPEG quality with which raster images are re-encoded in PDF export.
+    pub pdf_image_quality: Option<u8>,
     /// A destination to write a list of dependencies to.
     pub deps: Option<Output>,
     /// The format to use for dependencies.
     pub deps_format: DepsFormat,
     /// The PPI (pixels per inch) to use for PNG export.
     pub ppi: f64,
+    /// Whether to draw frame boundaries and baselines in PNG and SVG export.
+    pub debug_overlay: bool,
     /// The export cache for images, used for caching output files in `typst
     /// watch` sessions with images.
     pub export_cache: ExportCache,
@@ -177,6 +255,12 @@ impl CompileConfig {
             }
         }
 
+        if let Some(ppi) = args.pdf_image_max_ppi
+            && !(ppi.is_finite() && ppi > 0.0)
+        {
+            bail!("PDF image PPI must be positive");
+        }
+
         let pdf_standards = PdfStandards::new(
             &args.pdf_standard.iter().copied().map(Into::into).collect::<Vec<_>>(),
         )?;
@@ -208,6 +292,42 @@ impl CompileConfig {
             );
         }
 
+        if args.debug_overlay
+            && !matches!(output_format, OutputFormat::Png | OutputFormat::Svg)
+        {
+            warnings
+                .push("--debug-overlay only has an effect on PNG and SVG export".into());
+        }
+
+        if args.records.is_some() {
+            if watch.is_some() {
+                bail!("cannot compile records in watch mode");
+            }
+
+            let Output::Path(path) = &output else {
+                bail!("cannot write records to stdout");
+            };
+
+            if deps.is_some() {
+                bail!("cannot write dependencies when compiling records");
+            }
+
+            if args.open.is_some() {
+                bail!("cannot open the output when compiling records");
+            }
+
+            let Some(path) = path.to_str() else {
+                bail!("output path must be valid UTF-8 when compiling records");
+            };
+
+            if !output_template::has_record_template(path) {
+                bail!(
+                    "cannot compile records without a record number template \
+                     ({{r}}, {{0r}}) in the output path"
+                );
+            }
+        }
+
         match (&output, &deps, watch) {
             (Output::Stdout, _, Some(_)) => {
                 bail!("cannot write document to stdout in watch mode");
@@ -224,6 +344,7 @@ impl CompileConfig {
         Ok(Self {
             warnings,
             watching: watch.is_some(),
+            record: None,
             input,
             output,
             output_format,
@@ -231,6 +352,8 @@ impl CompileConfig {
             pages,
             pdf_standards,
             tagged,
+            pdf_image_max_ppi: args.pdf_image_max_ppi,
+            pdf_image_quality: args.pdf_image_quality,
             creation_timestamp: args
                 .world
                 .creation_timestamp
@@ -240,6 +363,7 @@ impl CompileConfig {
                 })
                 .transpose()?,
             ppi: args.ppi,
+            debug_overlay: args.debug_overlay,
             diagnostic_format: args.process.diagnostic_format,
             open: args.open.clone(),
             export_cache: ExportCache::new(),
@@ -286,6 +410,7 @@ pub fn compile_once(
                 }
             }
 
+            print_record(config, &warnings)?;
             print_diagnostics(world, &[], &warnings, config.diagnostic_format)
                 .map_err(|err| eco_format!("failed to print diagnostics ({err})"))?;
 
@@ -300,6 +425,7 @@ pub fn compile_once(
                 Status::Error.print(config).unwrap();
             }
 
+            print_record(config, errors)?;
             print_diagnostics(world, errors, &warnings, config.diagnostic_format)
                 .map_err(|err| eco_format!("failed to print diagnostics ({err})"))?;
         }
@@ -313,6 +439,21 @@ pub fn compile_once(
     Ok(())
 }
 
+/// Print which record the following diagnostics belong to, if compiling
+/// records and there are any diagnostics.
+fn print_record(
+    config: &CompileConfig,
+    diagnostics: &[SourceDiagnostic],
+) -> StrResult<()> {
+    let Some((this_record, total_records)) = config.record else { return Ok(()) };
+    if diagnostics.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(terminal::out(), "in record {this_record} of {total_records}:")
+        .map_err(|err| eco_format!("failed to print diagnostics ({err})"))
+}
+
 /// Compile and then export the document.
 fn compile_and_export(
     world: &mut SystemWorld,
@@ -535,17 +676,32 @@ fn export_image(
 
 mod output_template {
     const INDEXABLE: [&str; 3] = ["{p}", "{0p}", "{n}"];
+    const RECORD: [&str; 2] = ["{r}", "{0r}"];
 
     pub fn has_indexable_template(output: &str) -> bool {
         INDEXABLE.iter().any(|template| output.contains(template))
     }
 
-    pub fn format(output: &str, this_page: usize, total_pages: usize) -> String {
-        // Find the base 10 width of number `i`
-        fn width(i: usize) -> usize {
-            1 + i.checked_ilog10().unwrap_or(0) as usize
-        }
+    pub fn has_record_template(output: &str) -> bool {
+        RECORD.iter().any(|template| output.contains(template))
+    }
+
+    // Find the base 10 width of number `i`
+    fn width(i: usize) -> usize {
+        1 + i.checked_ilog10().unwrap_or(0) as usize
+    }
 
+    pub fn format_record(
+        output: &str,
+        this_record: usize,
+        total_records: usize,
+    ) -> String {
+        output
+            .replace("{r}", &this_record.to_string())
+            .replace("{0r}", &format!("{:01$}", this_record, width(total_records)))
+    }
+
+    pub fn format(output: &str, this_page: usize, total_pages: usize) -> String {
         let other_templates = ["{t}"];
         INDEXABLE.iter().chain(other_templates.iter()).fold(
             output.to_string(),
@@ -569,6 +725,14 @@ fn export_image_page(
     output: &Output,
     fmt: ImageExportFormat,
 ) -> StrResult<()> {
+    let storage;
+    let page = if config.debug_overlay {
+        storage = debug_overlay(page);
+        &storage
+    } else {
+        page
+    };
+
     match fmt {
         ImageExportFormat::Png => {
             let options = png_options(config);
@@ -591,6 +755,54 @@ fn export_image_page(
     Ok(())
 }
 
+/// Draws the boundaries of all frames and the baselines of all frames and text
+/// runs on top of a page's content.
+fn debug_overlay(page: &Page) -> Page {
+    let mut overlay = Frame::soft(page.frame.size());
+    add_debug_overlay(&mut overlay, &page.frame, Transform::identity());
+
+    let mut page = page.clone();
+    page.frame.push_frame(Point::zero(), overlay);
+    page
+}
+
+/// Adds the overlay shapes for a frame and its subframes, with `ts` mapping
+/// from the frame's coordinate system to the overlay's.
+fn add_debug_overlay(overlay: &mut Frame, frame: &Frame, ts: Transform) {
+    let bounds = FixedStroke::from_pair(Color::BLUE, Abs::pt(0.5));
+    let baseline = FixedStroke::from_pair(Color::RED, Abs::pt(0.5));
+
+    let mut shapes = Frame::soft(frame.size());
+    let mut push_line = |pos: Point, width: Abs| {
+        let line = Geometry::Line(Point::with_x(width)).stroked(baseline.clone());
+        shapes.push(pos, FrameItem::Shape(line, Span::detached()));
+    };
+
+    if frame.has_baseline() {
+        push_line(Point::with_y(frame.baseline()), frame.width());
+    }
+
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => {
+                let ts = ts
+                    .pre_concat(Transform::translate(pos.x, pos.y))
+                    .pre_concat(group.transform);
+                add_debug_overlay(overlay, &group.frame, ts);
+            }
+            FrameItem::Text(text) => push_line(*pos, text.width()),
+            _ => {}
+        }
+    }
+
+    let rect = Geometry::Rect(frame.size()).stroked(bounds);
+    shapes.push(Point::zero(), FrameItem::Shape(rect, Span::detached()));
+
+    let mut group = GroupItem::new(shapes);
+    group.transform = ts;
+    overlay.push(Point::zero(), FrameItem::Group(group));
+}
+
 /// Creates options for HTML export.
 fn html_options(config: &CompileConfig) -> HtmlOptions {
     HtmlOptions { pretty: config.pretty }
@@ -621,6 +833,8 @@ fn pdf_options(config: &CompileConfig) -> PdfOptions {
         standards: config.pdf_standards.clone(),
         tagged: config.tagged,
         pretty: config.pretty,
+        image_max_ppi: config.pdf_image_max_ppi,
+        image_jpeg_quality: config.pdf_image_quality,
     }
 }
 
@@ -12,7 +12,7 @@ use typst::syntax::{
 };
 use typst::text::{Font, FontBook};
 use typst::utils::LazyHash;
-use typst::{Library, LibraryExt, World};
+use typst::{Features, Library, LibraryExt, World};
 use typst_kit::datetime::Time;
 use typst_kit::diagnostics::DiagnosticWorld;
 use typst_kit::files::{FileLoader, FileStore, FsRoot};
@@ -27,6 +27,10 @@ pub struct SystemWorld {
     workdir: Option<PathBuf>,
     /// Typst's standard library.
     library: LazyHash<Library>,
+    /// The inputs passed on the command line, available through `sys.inputs`.
+    inputs: Dict,
+    /// The in-development features that are enabled.
+    features: Features,
     /// Metadata about discovered fonts and lazily loaded fonts.
     fonts: LazyLock<FontStore, Box<dyn Fn() -> FontStore + Send + Sync>>,
     /// Maps file ids to source files and buffers.
@@ -53,19 +57,20 @@ impl SystemWorld {
                 .ok();
         }
 
-        let library = {
-            // Convert the input pairs to a dictionary.
-            let inputs: Dict = world_args
-                .inputs
-                .iter()
-                .map(|(k, v)| (k.as_str().into(), v.as_str().into_value()))
-                .collect();
+        // Convert the input pairs to a dictionary.
+        let inputs: Dict = world_args
+            .inputs
+            .iter()
+            .map(|(k, v)| (k.as_str().into(), v.as_str().into_value()))
+            .collect();
 
-            let features =
-                process_args.features.iter().copied().map(Into::into).collect();
+        let features: Features =
+            process_args.features.iter().copied().map(Into::into).collect();
 
-            Library::builder().with_inputs(inputs).with_features(features).build()
-        };
+        let library = Library::builder()
+            .with_inputs(inputs.clone())
+            .with_features(features.clone())
+            .build();
 
         let now = match world_args.creation_timestamp {
             Some(time) => Time::fixed_timestamp(time)
@@ -76,6 +81,8 @@ impl SystemWorld {
         Ok(Self {
             workdir: std::env::current_dir().ok(),
             library: LazyHash::new(library),
+            inputs,
+            features,
             fonts: LazyLock::new(Box::new(|| {
                 crate::fonts::discover_fonts(&world_args.font)
             })),
@@ -106,6 +113,22 @@ impl SystemWorld {
         self.now.reset();
     }
 
+    /// Makes the given pairs available through `sys.inputs`, in addition to
+    /// the ones passed on the command line. Pairs from earlier calls are
+    /// discarded.
+    pub fn set_extra_inputs(&mut self, extra: Dict) {
+        let mut inputs = self.inputs.clone();
+        for (key, value) in extra {
+            inputs.insert(key, value);
+        }
+
+        let library = Library::builder()
+            .with_inputs(inputs)
+            .with_features(self.features.clone())
+            .build();
+        self.library = LazyHash::new(library);
+    }
+
     /// Forcibly scan fonts instead of doing it lazily upon the first access.
     ///
     /// Does nothing if the fonts were already scanned.
@@ -103,7 +103,7 @@ fn handle(
         converter.push(HtmlNode::text(' ', child.span()));
     } else if let Some(elem) = child.to_packed::<TextElem>() {
         let text = if let Some(case) = styles.get(TextElem::case) {
-            case.apply(&elem.text).into()
+            case.apply(&elem.text, Some(styles.get(TextElem::lang))).into()
         } else {
             elem.text.clone()
         };
@@ -162,7 +162,39 @@ pub fn system() -> impl Iterator<Item = (FontPath, FontInfo)> {
 #[cfg(feature = "scan-fonts")]
 pub fn scan(path: &std::path::Path) -> impl Iterator<Item = (FontPath, FontInfo)> {
     let _scope = typst_timing::TimingScope::new("scan system fonts");
-    with_db(move |db| db.load_fonts_dir(path))
+    let mut fonts: Vec<_> = with_db(move |db| db.load_fonts_dir(path)).collect();
+    scan_woff(path, &mut fonts, &mut std::collections::HashSet::new());
+    fonts.into_iter()
+}
+
+/// Recursively discovers WOFF fonts in a directory, which `fontdb` does not
+/// support.
+///
+/// Like `fontdb`, this follows symlinks to directories. The canonical paths of
+/// visited directories are tracked in `seen` to break symlink cycles.
+#[cfg(feature = "scan-fonts")]
+fn scan_woff(
+    dir: &std::path::Path,
+    fonts: &mut Vec<(FontPath, FontInfo)>,
+    seen: &mut std::collections::HashSet<PathBuf>,
+) {
+    let Ok(canonical) = dir.canonicalize() else { return };
+    if !seen.insert(canonical) {
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            scan_woff(&path, fonts, seen);
+        } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("woff"))
+            && let Ok(data) = fs::read(&path)
+            && let Some(info) = FontInfo::new(&data, 0)
+        {
+            fonts.push((FontPath { path, index: 0 }, info));
+        }
+    }
 }
 
 /// Discovers fonts via `fontdb`.
@@ -554,7 +554,30 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
 
         // Determine the region's size.
         let size = region.expand.select(region.size, used.min(region.size));
-        let free = size.y - used.y;
+        let mut free = size.y - used.y;
+
+        // When justifying vertically, stretch weak spacing between blocks and
+        // paragraphs to fill the remaining space. Leading (weakness 5) is left
+        // alone so that lines within a paragraph stay together. Spacing is
+        // never stretched to more than twice its amount; if that doesn't
+        // suffice, we leave the region as is.
+        let mut stretch = 0.0;
+        if self.composer.config.justify && !forced && frs.is_zero() && free > Abs::zero()
+        {
+            let weak: Abs = self
+                .items
+                .iter()
+                .map(|item| match *item {
+                    Item::Abs(amount, 1..=4) => amount,
+                    _ => Abs::zero(),
+                })
+                .sum();
+
+            if weak > Abs::zero() && free <= weak {
+                stretch = free / weak;
+                free = Abs::zero();
+            }
+        }
 
         let mut output = Frame::soft(size);
         let mut ruler = FixedAlignment::Start;
@@ -570,8 +593,11 @@ impl<'a, 'b> Distributor<'a, 'b, '_, '_, '_> {
                     let pos = Point::with_y(y);
                     output.push(pos, FrameItem::Tag(tag.clone()));
                 }
-                Item::Abs(v, _) => {
+                Item::Abs(v, weakness) => {
                     offset += v;
+                    if (1..=4).contains(&weakness) {
+                        offset += v * stretch;
+                    }
                 }
                 Item::Fr(v, _, single) => {
                     let length = v.share(frs, fr_space);
@@ -256,6 +256,7 @@ fn configuration<'x>(
             let dir = shared.resolve(TextElem::dir);
             ColumnConfig { count, width, gutter, dir }
         },
+        justify: mode == FlowMode::Root && shared.get(PageElem::justify),
         footnote: FootnoteConfig {
             separator: shared
                 .get_cloned(FootnoteEntry::separator)
@@ -362,6 +363,9 @@ struct Config<'x> {
     shared: StyleChain<'x>,
     /// Settings for columns.
     columns: ColumnConfig,
+    /// Whether to stretch weak spacing so that regions which were broken due
+    /// to a lack of space are filled to the bottom.
+    justify: bool,
     /// Settings for footnotes.
     footnote: FootnoteConfig,
     /// Settings for line numbers.
@@ -39,20 +39,26 @@ pub fn layout_cell(
     // grid cells could just be marked as locatable, but the tags are somehow
     // considered significant for layouting. This hack together with a check in
     // the grid layouter makes the test suite pass.
+    //
+    // The cell is also laid out with its `is_repeated` field set, so that show
+    // rules can observe whether it is being repeated.
     let mut locator = locator.split();
+    let mut body = cell.body.clone();
     let mut tags = None;
     if let Some(table_cell) = cell.body.to_packed::<TableCell>() {
         let mut table_cell = table_cell.clone();
-        table_cell.is_repeated.set(is_repeated);
+        table_cell.is_repeated = Some(is_repeated);
+        body = table_cell.clone().pack();
         tags = Some(generate_tags(table_cell, &mut locator, engine));
     } else if let Some(grid_cell) = cell.body.to_packed::<GridCell>() {
         let mut grid_cell = grid_cell.clone();
-        grid_cell.is_repeated.set(is_repeated);
+        grid_cell.is_repeated = Some(is_repeated);
+        body = grid_cell.clone().pack();
         tags = Some(generate_tags(grid_cell, &mut locator, engine));
     }
 
-    let locator = locator.next(&cell.body.span());
-    let fragment = crate::layout_fragment(engine, &cell.body, locator, styles, regions)?;
+    let locator = locator.next(&body.span());
+    let fragment = crate::layout_fragment(engine, &body, locator, styles, regions)?;
 
     // Manually insert tags.
     let mut frames = fragment.into_frames();
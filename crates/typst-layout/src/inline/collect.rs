@@ -160,7 +160,9 @@ pub fn collect<'a>(
                 }
 
                 if let Some(case) = styles.get(TextElem::case) {
-                    full.push_str(&case.apply(&elem.text));
+                    full.push_str(
+                        &case.apply(&elem.text, Some(styles.get(TextElem::lang))),
+                    );
                 } else {
                     full.push_str(&elem.text);
                 }
@@ -1,5 +1,6 @@
 use either::Either;
-use typst_library::layout::{Dir, Em};
+use typst_library::diag::bail;
+use typst_library::layout::{Axis, Dir, Em};
 use typst_library::text::TextElem;
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
 
@@ -70,6 +71,10 @@ pub fn prepare<'a>(
     segments: Vec<Segment<'a>>,
     spans: SpanMapper,
 ) -> SourceResult<Preparation<'a>> {
+    if config.dir.axis() != Axis::X {
+        bail!(spans.span_at(0).0, "vertical text layout is not supported");
+    }
+
     let default_level = match config.dir {
         Dir::RTL => BidiLevel::rtl(),
         _ => BidiLevel::ltr(),
@@ -984,10 +984,12 @@ fn shape_segment<'a>(
     }) {
         buffer.set_script(script)
     }
-    buffer.set_direction(match ctx.dir {
-        Dir::LTR => rustybuzz::Direction::LeftToRight,
-        Dir::RTL => rustybuzz::Direction::RightToLeft,
-        _ => unimplemented!("vertical text layout"),
+    // Shaping directions stem from the BiDi levels and are thus horizontal.
+    // Vertical base directions are rejected before shaping in `prepare`.
+    buffer.set_direction(if ctx.dir.is_positive() {
+        rustybuzz::Direction::LeftToRight
+    } else {
+        rustybuzz::Direction::RightToLeft
     });
     buffer.guess_segment_properties();
 
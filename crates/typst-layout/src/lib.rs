@@ -14,6 +14,7 @@ mod pages;
 mod repeat;
 mod rules;
 mod shapes;
+mod snapshot;
 mod stack;
 mod transforms;
 
@@ -22,3 +23,4 @@ pub use self::flow::{layout_fragment, layout_frame};
 pub use self::introspect::PagedIntrospector;
 pub use self::pages::{layout_document, layout_document_for_bundle};
 pub use self::rules::register;
+pub use self::snapshot::snapshot;
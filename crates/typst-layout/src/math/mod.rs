@@ -55,8 +55,6 @@ pub fn layout_equation_inline(
     styles: StyleChain,
     region: Size,
 ) -> SourceResult<Vec<InlineItem>> {
-    assert!(!elem.block.get(styles));
-
     let span = elem.span();
     let font = get_font(engine.world, styles, span)?;
     warn_non_math_font(&font, engine, span);
@@ -110,8 +108,6 @@ pub fn layout_equation_block(
     styles: StyleChain,
     regions: Regions,
 ) -> SourceResult<Fragment> {
-    assert!(elem.block.get(styles));
-
     let span = elem.span();
     let font = get_font(engine.world, styles, span)?;
     warn_non_math_font(&font, engine, span);
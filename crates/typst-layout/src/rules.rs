@@ -30,8 +30,8 @@ use typst_library::text::{
     TextSize, UnderlineElem, WeightDelta,
 };
 use typst_library::visualize::{
-    CircleElem, CurveElem, EllipseElem, ImageElem, LineElem, PolygonElem, RectElem,
-    SquareElem, Stroke,
+    BarcodeElem, CircleElem, CurveElem, EllipseElem, ImageElem, LineElem, PolygonElem,
+    QrCodeElem, RectElem, SquareElem, Stroke,
 };
 use typst_utils::{Get, Numeric};
 
@@ -101,6 +101,8 @@ pub fn register(rules: &mut NativeRuleMap) {
     rules.register(Paged, CIRCLE_RULE);
     rules.register(Paged, POLYGON_RULE);
     rules.register(Paged, CURVE_RULE);
+    rules.register(Paged, BARCODE_RULE);
+    rules.register(Paged, QRCODE_RULE);
 
     // Math.
     rules.register(Paged, EQUATION_RULE);
@@ -802,6 +804,14 @@ const CURVE_RULE: ShowFn<CurveElem> = |elem, _, _| {
     Ok(BlockElem::single_layouter(elem.clone(), crate::shapes::layout_curve).pack())
 };
 
+const BARCODE_RULE: ShowFn<BarcodeElem> = |elem, _, _| {
+    Ok(BlockElem::single_layouter(elem.clone(), crate::shapes::layout_barcode).pack())
+};
+
+const QRCODE_RULE: ShowFn<QrCodeElem> = |elem, _, _| {
+    Ok(BlockElem::single_layouter(elem.clone(), crate::shapes::layout_qrcode).pack())
+};
+
 const EQUATION_RULE: ShowFn<EquationElem> = |elem, _, styles| {
     if elem.block.get(styles) {
         Ok(BlockElem::multi_layouter(elem.clone(), crate::math::layout_equation_block)
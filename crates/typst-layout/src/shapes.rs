@@ -1,7 +1,7 @@
 use std::f64::consts::SQRT_2;
 
 use kurbo::{CubicBez, ParamCurveExtrema};
-use typst_library::diag::{SourceResult, bail};
+use typst_library::diag::{At, SourceResult, bail};
 use typst_library::engine::Engine;
 use typst_library::foundations::{Content, Packed, Resolve, Smart, StyleChain};
 use typst_library::introspection::Locator;
@@ -9,9 +9,9 @@ use typst_library::layout::{
     Abs, Axes, Corner, Corners, Frame, FrameItem, Point, Ratio, Region, Rel, Sides, Size,
 };
 use typst_library::visualize::{
-    CircleElem, CloseMode, Curve, CurveComponent, CurveElem, EllipseElem, FillRule,
-    FixedStroke, Geometry, LineCap, LineElem, Paint, PolygonElem, RectElem, Shape,
-    SquareElem, Stroke,
+    BarcodeElem, CircleElem, CloseMode, Curve, CurveComponent, CurveElem, EllipseElem,
+    FillRule, FixedStroke, Geometry, LineCap, LineElem, Paint, PolygonElem, QrCode,
+    QrCodeElem, RectElem, Shape, SquareElem, Stroke,
 };
 use typst_syntax::Span;
 use typst_utils::{Get, Numeric};
@@ -52,6 +52,95 @@ pub fn layout_line(
     Ok(frame)
 }
 
+/// Layout the barcode.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_barcode(
+    elem: &Packed<BarcodeElem>,
+    _: &mut Engine,
+    _: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let modules = elem.kind.get(styles).encode(&elem.data).at(elem.span())?;
+    let module = elem.module.resolve(styles);
+    let height = elem.height.resolve(styles);
+    let fill = elem.fill.get_cloned(styles);
+
+    if module <= Abs::zero() {
+        bail!(elem.span(), "barcode module width must be positive");
+    }
+
+    if height <= Abs::zero() {
+        bail!(elem.span(), "barcode height must be positive");
+    }
+
+    let width = module * modules.len() as f64;
+    let mut frame = Frame::soft(Size::new(width, height));
+
+    // Draw each run of consecutive bar modules as a single rectangle.
+    let mut start = 0;
+    while start < modules.len() {
+        let bar = modules[start];
+        let end = modules[start..]
+            .iter()
+            .position(|&m| m != bar)
+            .map_or(modules.len(), |len| start + len);
+
+        if bar {
+            let size = Size::new(module * (end - start) as f64, height);
+            let shape = Geometry::Rect(size).filled(fill.clone());
+            let pos = Point::with_x(module * start as f64);
+            frame.push(pos, FrameItem::Shape(shape, elem.span()));
+        }
+
+        start = end;
+    }
+
+    Ok(frame)
+}
+
+/// Layout the QR code.
+#[typst_macros::time(span = elem.span())]
+pub fn layout_qrcode(
+    elem: &Packed<QrCodeElem>,
+    _: &mut Engine,
+    _: Locator,
+    styles: StyleChain,
+    _: Region,
+) -> SourceResult<Frame> {
+    let qr =
+        QrCode::encode(elem.data.as_bytes(), elem.level.get(styles)).at(elem.span())?;
+    let module = elem.module.resolve(styles);
+    let fill = elem.fill.get_cloned(styles);
+
+    if module <= Abs::zero() {
+        bail!(elem.span(), "QR code module size must be positive");
+    }
+
+    let size = qr.size();
+    let mut frame = Frame::soft(Size::splat(module * size as f64));
+
+    // Draw each horizontal run of dark modules as a single rectangle.
+    for y in 0..size {
+        let mut x = 0;
+        while x < size {
+            let dark = qr.get(x, y);
+            let end = (x..size).find(|&end| qr.get(end, y) != dark).unwrap_or(size);
+
+            if dark {
+                let size = Size::new(module * (end - x) as f64, module);
+                let shape = Geometry::Rect(size).filled(fill.clone());
+                let pos = Point::new(module * x as f64, module * y as f64);
+                frame.push(pos, FrameItem::Shape(shape, elem.span()));
+            }
+
+            x = end;
+        }
+    }
+
+    Ok(frame)
+}
+
 /// Layout the curve.
 #[typst_macros::time(span = elem.span())]
 pub fn layout_curve(
@@ -0,0 +1,175 @@
+//! Stable textual snapshots of laid out documents.
+
+use std::fmt::Write;
+
+use typst_library::layout::{Abs, Frame, FrameItem, Point, Size};
+use typst_library::visualize::Geometry;
+
+use crate::{Page, PagedDocument};
+
+/// Renders a document into a stable, human-readable outline of its pages.
+///
+/// Each line describes one frame item with its kind, its position relative to
+/// the enclosing frame, and its most relevant properties. Lengths are rounded
+/// to two decimal places in points, so that the output does not change with
+/// floating point noise. Introspection tags are omitted.
+///
+/// This is meant for regression tests that should be sensitive to layout
+/// changes, but easier to review than rendered images. Like the
+/// [`PagedDocument`] it takes, it lives in this crate and is not re-exported
+/// by the `typst` facade. Downstream test suites depend on `typst-layout` and
+/// call it as `typst_layout::snapshot` on the output of
+/// `typst::compile::<PagedDocument>`.
+pub fn snapshot(document: &PagedDocument) -> String {
+    let mut out = String::new();
+    for (i, page) in document.pages().iter().enumerate() {
+        snapshot_page(&mut out, i + 1, page);
+    }
+    out
+}
+
+/// Writes the snapshot of a single page.
+fn snapshot_page(out: &mut String, number: usize, page: &Page) {
+    writeln!(out, "page {number} {}", size(page.frame.size())).unwrap();
+    snapshot_frame(out, &page.frame, 1);
+}
+
+/// Writes the snapshot of a frame's items at the given indentation level.
+fn snapshot_frame(out: &mut String, frame: &Frame, depth: usize) {
+    let items = frame.items().filter(|(_, item)| !matches!(item, FrameItem::Tag(_)));
+    for (pos, item) in items {
+        let indent = "  ".repeat(depth);
+        write!(out, "{indent}{} ", point(*pos)).unwrap();
+        match item {
+            FrameItem::Group(group) => {
+                write!(out, "group {}", size(group.frame.size())).unwrap();
+                if !group.transform.is_identity() {
+                    out.push_str(" transformed");
+                }
+                if group.clip.is_some() {
+                    out.push_str(" clipped");
+                }
+                out.push('\n');
+                snapshot_frame(out, &group.frame, depth + 1);
+            }
+            FrameItem::Text(text) => {
+                writeln!(
+                    out,
+                    "text {:?} {} {}",
+                    text.text.as_str(),
+                    text.font.font().info().family,
+                    abs(text.size),
+                )
+                .unwrap();
+            }
+            FrameItem::Shape(shape, _) => {
+                let kind = match &shape.geometry {
+                    Geometry::Line(to) => format!("line to {}", point(*to)),
+                    Geometry::Rect(rect) => format!("rect {}", size(*rect)),
+                    Geometry::Curve(_) => "curve".into(),
+                };
+                writeln!(out, "shape {kind}").unwrap();
+            }
+            FrameItem::Image(_, image_size, _) => {
+                writeln!(out, "image {}", size(*image_size)).unwrap();
+            }
+            FrameItem::Link(_, link_size) => {
+                writeln!(out, "link {}", size(*link_size)).unwrap();
+            }
+            FrameItem::Tag(_) => {}
+        }
+    }
+}
+
+/// Formats a point as `(x, y)`.
+fn point(point: Point) -> String {
+    format!("({}, {})", abs(point.x), abs(point.y))
+}
+
+/// Formats a size as `w×h`.
+fn size(size: Size) -> String {
+    format!("{}×{}", abs(size.x), abs(size.y))
+}
+
+/// Formats a length in points, rounded to two decimal places.
+fn abs(abs: Abs) -> String {
+    // Adding zero turns a negative zero into a positive one.
+    format!("{}pt", (abs.to_pt() * 100.0).round() / 100.0 + 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use ecow::eco_vec;
+    use typst_library::foundations::{Bytes, Content, Smart};
+    use typst_library::introspection::{Location, Tag, TagFlags};
+    use typst_library::layout::{Em, GroupItem, Ratio, Sides, Transform};
+    use typst_library::model::DocumentInfo;
+    use typst_library::text::{Font, Glyph, TextItem};
+    use typst_library::visualize::Color;
+    use typst_syntax::Span;
+
+    use super::*;
+
+    #[test]
+    fn test_snapshot_document() {
+        let font = typst_dev_assets::fonts()
+            .flat_map(|data| Font::iter(Bytes::new(data)))
+            .find(|font| font.info().family == "Libertinus Serif")
+            .unwrap();
+        let glyph = Glyph {
+            id: 0,
+            x_advance: Em::new(1.0),
+            x_offset: Em::zero(),
+            y_advance: Em::zero(),
+            y_offset: Em::zero(),
+            range: 0..2,
+            span: (Span::detached(), 0),
+        };
+        let text = TextItem::plain(&font, Abs::pt(10.0), "Hi".into(), vec![glyph]);
+
+        let tag = Tag::End(
+            Location::new(0),
+            0,
+            TagFlags { introspectable: false, tagged: false },
+        );
+        let mut inner = Frame::soft(Size::new(Abs::pt(40.0), Abs::pt(20.0)));
+        inner.push(Point::with_y(Abs::pt(15.0)), FrameItem::Text(text));
+        inner.push(Point::zero(), FrameItem::Tag(tag));
+
+        let mut group = GroupItem::new(inner);
+        group.transform = Transform::scale(Ratio::new(2.0), Ratio::one());
+
+        let rect = Geometry::Rect(Size::splat(Abs::pt(5.125)));
+        let mut frame = Frame::soft(Size::new(Abs::pt(100.0), Abs::pt(50.0)));
+        frame.push(Point::splat(Abs::pt(10.0)), FrameItem::Group(group));
+        frame.push(
+            Point::new(Abs::pt(60.0), Abs::pt(-0.001)),
+            FrameItem::Shape(rect.filled(Color::BLACK), Span::detached()),
+        );
+
+        let page = Page {
+            frame,
+            bleed: Sides::splat(Abs::zero()),
+            fill: Smart::Auto,
+            numbering: None,
+            supplement: Content::empty(),
+            number: 1,
+        };
+        let document = PagedDocument::new(eco_vec![page], DocumentInfo::default());
+
+        assert_eq!(
+            snapshot(&document),
+            "page 1 100pt×50pt\n\
+             \x20 (10pt, 10pt) group 40pt×20pt transformed\n\
+             \x20   (0pt, 15pt) text \"Hi\" Libertinus Serif 10pt\n\
+             \x20 (60pt, 0pt) shape rect 5.13pt×5.13pt\n",
+        );
+    }
+
+    #[test]
+    fn test_snapshot_abs_rounding() {
+        assert_eq!(abs(Abs::pt(12.3456)), "12.35pt");
+        assert_eq!(abs(Abs::pt(-0.001)), "0pt");
+        assert_eq!(abs(Abs::pt(-1.5)), "-1.5pt");
+    }
+}
@@ -1,4 +1,4 @@
-use std::num::ParseFloatError;
+use std::num::{NonZeroUsize, ParseFloatError};
 
 use ecow::{EcoString, eco_format};
 use typst_utils::Scalar;
@@ -184,6 +184,150 @@ impl f64 {
             _ => bail!("size must be either 4 or 8"),
         })
     }
+
+    /// Formats a float for display, with control over precision and notation.
+    ///
+    /// The result is a string that can be used both in markup and in math.
+    /// Negative numbers use a proper minus sign. To format an integer, convert
+    /// it to a float first.
+    ///
+    /// ```example
+    /// #1234.5678.format(digits: 3) \
+    /// #1234.5678.format(digits: 3, scientific: true) \
+    /// #9.81.format(uncertainty: 0.04) \
+    /// #0.000123.format(scientific: true)
+    /// ```
+    #[func]
+    pub fn format(
+        self,
+        /// The number of significant figures to show.
+        ///
+        /// If `{none}`, the number is shown with as many digits as needed to
+        /// represent it exactly or, if an uncertainty is given, up to the
+        /// first significant digit of the uncertainty. Values above 17 are
+        /// treated as 17, which is enough to represent any float exactly.
+        #[named]
+        digits: Option<NonZeroUsize>,
+        /// Whether to use scientific notation, as in `1.23 × 10⁴`.
+        #[named]
+        #[default(false)]
+        scientific: bool,
+        /// An uncertainty to show in concise notation, as in `1.23(4)`.
+        ///
+        /// If the last digit shown is after the decimal point, the uncertainty
+        /// is expressed in units of that digit. Otherwise, it is shown rounded
+        /// to the same place as the number, as in `12300(400)`.
+        #[named]
+        uncertainty: Option<f64>,
+    ) -> StrResult<Str> {
+        if uncertainty.is_some_and(|u| !u.is_finite() || u <= 0.0) {
+            bail!("uncertainty must be positive and finite");
+        }
+
+        if !self.is_finite() {
+            return Ok(repr::display_float(self).into());
+        }
+
+        let value = self.abs();
+
+        // More digits than this cannot carry any information for a float.
+        let digits = digits.map(|digits| digits.get().min(MAX_DIGITS));
+
+        // The power of ten of the last digit to show, if fixed.
+        let last = match (digits, uncertainty) {
+            (Some(digits), _) => {
+                let rounded = format!("{:.*e}", digits - 1, value);
+                Some(exponent(&rounded) - (digits as i32 - 1))
+            }
+            (None, Some(u)) => Some(exponent(&format!("{u:.0e}"))),
+            (None, None) => None,
+        };
+
+        let mut out = EcoString::new();
+        if self.is_sign_negative() && value != 0.0 {
+            out.push_str(repr::MINUS_SIGN);
+        }
+
+        let mut power = None;
+        if scientific {
+            let formatted = match last {
+                Some(last) => {
+                    // Rounding can carry over into the next power of ten, in
+                    // which case one fewer decimal is needed.
+                    let mut e = exponent(&format!("{value:e}"));
+                    let mut formatted = String::new();
+                    for _ in 0..2 {
+                        formatted = format!("{:.*e}", (e - last).max(0) as usize, value);
+                        if exponent(&formatted) == e {
+                            break;
+                        }
+                        e = exponent(&formatted);
+                    }
+                    formatted
+                }
+                None => format!("{value:e}"),
+            };
+            let (mantissa, e) = formatted.split_once('e').unwrap();
+            out.push_str(mantissa);
+            power = e.parse::<i32>().ok();
+        } else {
+            match last {
+                Some(last) if last > 0 => {
+                    let scale = 10f64.powi(last);
+                    out.push_str(&eco_format!("{}", (value / scale).round() * scale));
+                }
+                Some(last) => {
+                    out.push_str(&eco_format!("{:.*}", (-last) as usize, value))
+                }
+                None => out.push_str(&eco_format!("{value}")),
+            }
+        }
+
+        if let (Some(u), Some(last)) = (uncertainty, last) {
+            let mut units = (u / 10f64.powi(last)).round();
+            if !scientific && last > 0 {
+                units *= 10f64.powi(last);
+            }
+            out.push_str(&eco_format!("({units})"));
+        }
+
+        if let Some(power) = power {
+            out.push_str(" × 10");
+            for c in power.to_string().chars() {
+                out.push(superscript(c));
+            }
+        }
+
+        Ok(out.into())
+    }
+}
+
+/// The maximum number of significant digits needed to represent any float.
+const MAX_DIGITS: usize = 17;
+
+/// Extracts the exponent from a float formatted in Rust's `{:e}` notation.
+fn exponent(formatted: &str) -> i32 {
+    formatted
+        .split_once('e')
+        .and_then(|(_, e)| e.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Maps a digit or minus sign to its superscript form.
+fn superscript(c: char) -> char {
+    match c {
+        '0' => '⁰',
+        '1' => '¹',
+        '2' => '²',
+        '3' => '³',
+        '4' => '⁴',
+        '5' => '⁵',
+        '6' => '⁶',
+        '7' => '⁷',
+        '8' => '⁸',
+        '9' => '⁹',
+        _ => '⁻',
+    }
 }
 
 impl Repr for f64 {
@@ -10,7 +10,7 @@ use crate::foundations::Label;
 use crate::introspection::{Location, Tag};
 use crate::layout::{Abs, Axes, FixedAlignment, Point, Size, Transform};
 use crate::model::Destination;
-use crate::text::TextItem;
+use crate::text::{Glyph, TextItem};
 use crate::visualize::{Color, Curve, FixedStroke, Geometry, Image, Paint, Shape};
 
 /// A finished layout with items at fixed positions.
@@ -147,6 +147,53 @@ impl Frame {
     pub fn items(&self) -> std::slice::Iter<'_, (Point, FrameItem)> {
         self.items.iter()
     }
+
+    /// Collects all glyphs in this frame and its subframes in paint order,
+    /// alongside their placement relative to the top-left of the frame.
+    ///
+    /// Useful for tooling that checks glyph positioning or the text that
+    /// glyphs map back to, like visual regression or copy-paste tests.
+    pub fn glyphs(&self) -> Vec<PositionedGlyph<'_>> {
+        let mut glyphs = vec![];
+        self.collect_glyphs(Transform::identity(), &mut glyphs);
+        glyphs
+    }
+
+    /// Recursively collects the glyphs of this frame, with `ts` mapping from
+    /// the frame's coordinate system to the outermost one.
+    fn collect_glyphs<'a>(
+        &'a self,
+        ts: Transform,
+        glyphs: &mut Vec<PositionedGlyph<'a>>,
+    ) {
+        for (pos, item) in self.items() {
+            let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+            match item {
+                FrameItem::Group(group) => {
+                    group.frame.collect_glyphs(ts.pre_concat(group.transform), glyphs);
+                }
+                FrameItem::Text(text) => {
+                    let mut cursor = Point::zero();
+                    for glyph in &text.glyphs {
+                        let offset = Point::new(
+                            cursor.x + glyph.x_offset.at(text.size),
+                            cursor.y - glyph.y_offset.at(text.size),
+                        );
+                        glyphs.push(PositionedGlyph {
+                            item: text,
+                            glyph,
+                            text: &text.text[glyph.range()],
+                            transform: ts
+                                .pre_concat(Transform::translate(offset.x, offset.y)),
+                        });
+                        cursor.x += glyph.x_advance.at(text.size);
+                        cursor.y -= glyph.y_advance.at(text.size);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
 }
 
 /// Insert items and subframes.
@@ -511,6 +558,21 @@ impl Debug for FrameItem {
     }
 }
 
+/// A glyph with its placement in a frame, as yielded by [`Frame::glyphs`].
+#[derive(Debug, Clone)]
+pub struct PositionedGlyph<'a> {
+    /// The text run the glyph is part of, with its font, size, and paint.
+    pub item: &'a TextItem,
+    /// The glyph itself.
+    pub glyph: &'a Glyph,
+    /// The text of the glyph's cluster.
+    pub text: &'a str,
+    /// Maps from the glyph's origin on the baseline to the frame's coordinate
+    /// system. Since glyph outlines are Y-up, they need to be flipped
+    /// vertically before applying this transform.
+    pub transform: Transform,
+}
+
 /// A subframe with optional transformation and clipping.
 #[derive(Clone, Hash)]
 pub struct GroupItem {
@@ -594,3 +656,53 @@ pub enum Inherit {
     Yes,
     No,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundations::Bytes;
+    use crate::layout::{Em, Ratio};
+    use crate::text::Font;
+
+    #[test]
+    fn test_frame_glyphs() {
+        let data = typst_dev_assets::fonts().next().unwrap();
+        let font = Font::new(Bytes::new(data), 0).unwrap();
+        let size = Abs::pt(10.0);
+        let glyph = |x_advance, y_offset, range| Glyph {
+            id: 0,
+            x_advance: Em::new(x_advance),
+            x_offset: Em::zero(),
+            y_advance: Em::zero(),
+            y_offset: Em::new(y_offset),
+            range,
+            span: (Span::detached(), 0),
+        };
+
+        // A ligature spanning two characters followed by a raised glyph.
+        let glyphs = vec![glyph(0.5, 0.0, 0..2), glyph(0.25, 0.25, 2..3)];
+        let text = TextItem::plain(&font, size, "fix".into(), glyphs);
+
+        let mut inner = Frame::soft(Size::splat(Abs::pt(20.0)));
+        inner.push(Point::new(Abs::pt(1.0), Abs::pt(2.0)), FrameItem::Text(text));
+
+        let mut group = GroupItem::new(inner);
+        group.transform = Transform::scale(Ratio::new(2.0), Ratio::new(2.0));
+
+        let mut outer = Frame::soft(Size::splat(Abs::pt(50.0)));
+        outer.push(Point::splat(Abs::pt(5.0)), FrameItem::Group(group));
+
+        let glyphs = outer.glyphs();
+        let placed = |tx, ty| Transform {
+            tx: Abs::pt(tx),
+            ty: Abs::pt(ty),
+            ..Transform::scale(Ratio::new(2.0), Ratio::new(2.0))
+        };
+
+        assert_eq!(glyphs.len(), 2);
+        assert_eq!(glyphs[0].text, "fi");
+        assert_eq!(glyphs[0].transform, placed(7.0, 9.0));
+        assert_eq!(glyphs[1].text, "x");
+        assert_eq!(glyphs[1].transform, placed(17.0, 4.0));
+    }
+}
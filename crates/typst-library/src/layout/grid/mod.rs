@@ -851,14 +851,16 @@ pub struct GridCell {
     #[fold]
     pub stroke: Sides<Option<Option<Arc<Stroke>>>>,
 
-    #[internal]
-    #[parse(Some(false))]
-    pub is_repeated: bool,
-
     /// Whether rows spanned by this cell can be placed in different pages. When
     /// equal to `{auto}`, a cell spanning only fixed-size rows is unbreakable,
     /// while a cell spanning at least one `{auto}`-sized row is breakable.
     pub breakable: Smart<bool>,
+
+    /// Whether this cell is a repetition of a header or footer cell in a
+    /// later region. Functions identically to the `is-repeated` field in
+    /// @table.cell.
+    #[synthesized]
+    pub is_repeated: bool,
 }
 
 cast! {
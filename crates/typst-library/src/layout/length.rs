@@ -7,8 +7,10 @@ use ecow::{EcoString, eco_format};
 use typst_syntax::Span;
 use typst_utils::{Numeric, NumericLength};
 
-use crate::diag::{HintedStrResult, SourceResult, bail};
-use crate::foundations::{Context, Fold, Repr, Resolve, StyleChain, func, scope, ty};
+use crate::diag::{HintedStrResult, SourceResult, StrResult, bail};
+use crate::foundations::{
+    Context, Fold, Repr, Resolve, Str, StyleChain, cast, func, repr, scope, ty,
+};
 use crate::layout::{Abs, Em};
 
 /// A size or distance, possibly expressed with contextual units.
@@ -95,6 +97,30 @@ impl Length {
 
 #[scope]
 impl Length {
+    /// Converts a value to a length.
+    ///
+    /// Strings are parsed as a number followed by one of the units `pt`, `mm`,
+    /// `cm`, `in`, or `em`. Multiple such terms can be combined with `+` and
+    /// `-`, as in the @repr[representation] of a length with both an absolute
+    /// and a font-relative component. This is useful for working with lengths
+    /// stored in loaded data.
+    ///
+    /// ```example
+    /// #let data = json(bytes(
+    ///   "{ \"width\": \"3.5cm\" }"
+    /// ))
+    ///
+    /// #rect(width: length(data.width))
+    /// #length("2pt + 1.5em")
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The value that should be converted to a length.
+        value: ToLength,
+    ) -> Length {
+        value.0
+    }
+
     /// Converts this length to points.
     ///
     /// Fails with an error if this length has non-zero `em` units (such as
@@ -160,6 +186,65 @@ impl Length {
     }
 }
 
+/// A value that can be cast to a length.
+pub struct ToLength(Length);
+
+cast! {
+    ToLength,
+    v: Length => Self(v),
+    v: Str => Self(parse_length(&v)?),
+}
+
+/// The units that [`parse_length`] accepts.
+const UNITS: [&str; 5] = ["pt", "mm", "cm", "in", "em"];
+
+/// Parses a length from a string like `{"3.5cm"}` or `{"2pt - 1.5em"}`.
+fn parse_length(s: &str) -> StrResult<Length> {
+    let invalid = || eco_format!("invalid length: {s}");
+    let text = s.replace(repr::MINUS_SIGN, "-");
+    let mut length = Length::zero();
+    let mut negate = false;
+    let mut start = 0;
+
+    // A `+` or `-` separates two terms if it follows a unit. Otherwise, it
+    // belongs to a number, as in `-2pt` or `1e-3pt`. A trailing `+` closes
+    // the last term.
+    for (i, c) in text.char_indices().chain([(text.len(), '+')]) {
+        let term = text[start..i].trim();
+        if matches!(c, '+' | '-') && UNITS.iter().any(|unit| term.ends_with(unit)) {
+            let value = parse_length_term(term).ok_or_else(invalid)?;
+            length = length + if negate { -value } else { value };
+            negate = c == '-';
+            start = i + 1;
+        }
+    }
+
+    if start <= text.len() {
+        return Err(invalid());
+    }
+
+    Ok(length)
+}
+
+/// Parses a single number with a unit.
+fn parse_length_term(term: &str) -> Option<Length> {
+    let (number, unit) = UNITS
+        .into_iter()
+        .find_map(|unit| Some((term.strip_suffix(unit)?, unit)))?;
+    let value: f64 = number.trim_end().parse().ok()?;
+    if !value.is_finite() {
+        return None;
+    }
+
+    Some(match unit {
+        "pt" => Abs::pt(value).into(),
+        "mm" => Abs::mm(value).into(),
+        "cm" => Abs::cm(value).into(),
+        "in" => Abs::inches(value).into(),
+        _ => Em::new(value).into(),
+    })
+}
+
 impl Debug for Length {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match (self.abs.is_zero(), self.em.is_zero()) {
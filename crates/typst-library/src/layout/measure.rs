@@ -4,10 +4,11 @@ use typst_syntax::Span;
 use crate::diag::{At, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    Content, Context, Dict, Resolve, Smart, Target, TargetElem, dict, func,
+    Array, CastInfo, Content, Context, Dict, IntoValue, Reflect, Resolve, Smart, Target,
+    TargetElem, Value, dict, func,
 };
 use crate::introspection::{Locator, LocatorLink};
-use crate::layout::{Abs, Axes, Length, Region, Size};
+use crate::layout::{Abs, Axes, Length, Region, Regions, Size};
 
 /// Measures the layouted size of content.
 ///
@@ -42,7 +43,27 @@ use crate::layout::{Abs, Axes, Length, Region, Size};
 /// ```
 ///
 /// The measure function returns a dictionary with the entries `width` and
-/// `height`, both of type @length.
+/// `height`, both of type @length. When measuring across regions as described
+/// below, it instead returns an array of such dictionaries.
+///
+/// = Measuring across regions <regions>
+/// Breakable content such as long text can span multiple pages or columns.
+/// With `split` enabled, the content is laid out into as many regions of the
+/// given size as needed, and `measure` returns an array with one dictionary
+/// per region. This way, you can, for instance, decide on a layout based on
+/// how many pages some content would take up.
+///
+/// ```example
+/// #context {
+///   let regions = measure(
+///     width: 200pt,
+///     height: 100pt,
+///     split: true,
+///     lorem(120),
+///   )
+///   [Needs #regions.len() regions.]
+/// }
+/// ```
 #[func(contextual)]
 pub fn measure(
     engine: &mut Engine,
@@ -71,17 +92,23 @@ pub fn measure(
     #[named]
     #[default(Smart::Auto)]
     height: Smart<Length>,
+    /// Whether to lay out the content into as many regions of the available
+    /// size as needed instead of a single one.
+    ///
+    /// If `{true}`, an array with one size dictionary per region is returned.
+    /// This is only meaningful if a `height` is given, since there is just a
+    /// single region of infinite height otherwise.
+    #[named]
+    #[default(false)]
+    split: bool,
     /// The content whose size to measure.
     content: Content,
-) -> SourceResult<Dict> {
-    // Create a pod region with the available space.
+) -> SourceResult<Measurement> {
+    // Determine the available space.
     let styles = context.styles().at(span)?;
-    let pod = Region::new(
-        Axes::new(
-            width.resolve(styles).unwrap_or(Abs::inf()),
-            height.resolve(styles).unwrap_or(Abs::inf()),
-        ),
-        Axes::splat(false),
+    let size = Size::new(
+        width.resolve(styles).unwrap_or(Abs::inf()),
+        height.resolve(styles).unwrap_or(Abs::inf()),
     );
 
     // We put the locator into a special "measurement mode" to ensure that
@@ -93,13 +120,63 @@ pub fn measure(
     let locator = Locator::link(&link);
     let style = TargetElem::target.set(Target::Paged).wrap();
 
+    if split {
+        let fragment = (engine.library.routines.layout_fragment)(
+            engine,
+            &content,
+            locator,
+            styles.chain(&style),
+            Regions::repeat(size, Axes::splat(false)),
+        )?;
+        let sizes = fragment
+            .iter()
+            .map(|frame| to_dict(frame.size()).into_value())
+            .collect();
+        return Ok(Measurement::Split(sizes));
+    }
+
     let frame = (engine.library.routines.layout_frame)(
         engine,
         &content,
         locator,
         styles.chain(&style),
-        pod,
+        Region::new(size, Axes::splat(false)),
     )?;
-    let Size { x, y } = frame.size();
-    Ok(dict! { "width" => x, "height" => y })
+    Ok(Measurement::Single(to_dict(frame.size())))
+}
+
+/// The result of [`measure`].
+pub enum Measurement {
+    /// The size of content laid out into a single region.
+    Single(Dict),
+    /// One size per region, when measuring with `split`.
+    Split(Array),
+}
+
+impl Reflect for Measurement {
+    fn input() -> CastInfo {
+        Dict::input() + Array::input()
+    }
+
+    fn output() -> CastInfo {
+        Dict::output() + Array::output()
+    }
+
+    fn castable(value: &Value) -> bool {
+        Dict::castable(value) || Array::castable(value)
+    }
+}
+
+impl IntoValue for Measurement {
+    fn into_value(self) -> Value {
+        match self {
+            Self::Single(size) => size.into_value(),
+            Self::Split(sizes) => sizes.into_value(),
+        }
+    }
+}
+
+/// Turns a size into a dictionary with `width` and `height` entries.
+fn to_dict(Size { x, y }: Size) -> Dict {
+    dict! { "width" => x, "height" => y }
 }
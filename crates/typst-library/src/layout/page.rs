@@ -254,6 +254,32 @@ pub struct PageElem {
     #[ghost]
     pub columns: NonZeroUsize,
 
+    /// Whether to vertically justify the page's content.
+    ///
+    /// When enabled, the spacing between blocks and paragraphs on a page that
+    /// is broken due to a lack of space is stretched, such that the last line
+    /// ends flush with the bottom of the page. This way, facing pages of a book
+    /// end at the same depth. Spacing is stretched to at most twice its
+    /// original amount. If that is not enough to fill the page, the page is
+    /// left as is.
+    ///
+    /// Pages that end with an explicit page or column break and the last page
+    /// of a document are never justified. The same goes for pages with
+    /// @v[fractional spacing].
+    ///
+    /// ```example
+    /// >>> #set page(height: 140pt)
+    /// #set page(justify: true)
+    /// = Introduction
+    /// #lorem(10)
+    ///
+    /// = Background
+    /// #lorem(30)
+    /// ```
+    #[default(false)]
+    #[ghost]
+    pub justify: bool,
+
     /// The page's background fill.
     ///
     /// Setting this to something non-transparent instructs the printer to color
@@ -1,18 +1,22 @@
 use std::num::{NonZeroU32, NonZeroUsize};
 use std::sync::Arc;
 
+use comemo::Tracked;
 use ecow::EcoString;
+use smallvec::smallvec;
+use typst_syntax::Span;
 use typst_utils::NonZeroExt;
 
-use crate::diag::{HintedStrResult, HintedString, SourceResult, bail};
+use crate::diag::{At, HintedStrResult, HintedString, SourceResult, bail};
 use crate::engine::Engine;
 use crate::foundations::{
-    Content, Packed, Smart, StyleChain, Synthesize, cast, elem, scope,
+    Content, Context, Dict, Func, IntoValue, NativeElement, Packed, Repr, Smart, Str,
+    StyleChain, Synthesize, Value, cast, elem, func, scope,
 };
 use crate::layout::resolve::{CellGrid, table_to_cellgrid};
 use crate::layout::{
     Abs, Alignment, Celled, GridCell, GridFooter, GridHLine, GridHeader, GridVLine,
-    Length, OuterHAlignment, OuterVAlignment, Rel, Sides, TrackSizings,
+    Length, OuterHAlignment, OuterVAlignment, Rel, Sides, Sizing, TrackSizings,
 };
 use crate::model::Figurable;
 use crate::pdf::TableCellKind;
@@ -302,6 +306,150 @@ impl TableElem {
 
     #[elem]
     type TableFooter;
+
+    /// Creates a table from an array of dictionaries, such as the rows of a
+    /// @csv[CSV file] loaded with `{row-type: dictionary}` or a
+    /// @json[JSON array] of objects.
+    ///
+    /// Each dictionary becomes one row and each key one column. Columns in
+    /// which all values are numbers, or strings holding numbers as in CSV
+    /// data, are aligned to the right. Table properties other than `columns`
+    /// and `align` can be configured with set rules as usual.
+    ///
+    /// ```example
+    /// #let fruits = (
+    ///   (name: "Apple", count: 12, price: 0.5),
+    ///   (name: "Pear", count: 4, price: 0.75),
+    /// )
+    ///
+    /// #table.from(
+    ///   fruits,
+    ///   format: (price: p => [\$#p]),
+    /// )
+    /// ```
+    #[func(title = "Table from Data")]
+    pub fn from(
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// The rows of the table.
+        data: Vec<Dict>,
+        /// The keys to show as columns, in order. Defaults to all keys in
+        /// order of their first appearance in the data.
+        #[named]
+        keys: Option<Vec<Str>>,
+        /// Whether to add a header row with the keys.
+        #[named]
+        #[default(true)]
+        header: bool,
+        /// Functions that format the values of specific columns, keyed by
+        /// column. Values in other columns are displayed as is.
+        #[named]
+        #[default]
+        format: Dict,
+        /// How to align the cells, as for the table's
+        /// @table.align[`align`] property.
+        ///
+        /// If `{auto}`, numeric columns are aligned to the right and a
+        /// `{set table(align: ..)}` rule only applies if no column is numeric,
+        /// since the inferred alignment is set on the table itself.
+        #[named]
+        #[default]
+        align: Smart<Celled<Smart<Alignment>>>,
+    ) -> SourceResult<Content> {
+        let keys = keys.unwrap_or_else(|| {
+            let mut keys = Vec::<Str>::new();
+            for key in data.iter().flat_map(|row| row.iter().map(|(key, _)| key)) {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+            keys
+        });
+
+        if keys.is_empty() {
+            bail!(span, "table must have at least one column");
+        }
+
+        if let Some((key, _)) = format.iter().find(|(key, _)| !keys.contains(key)) {
+            bail!(span, "format contains unknown column {}", key.repr());
+        }
+
+        let formatters = keys
+            .iter()
+            .map(|key| {
+                format.get(key).ok().cloned().map(|v| v.cast::<Func>()).transpose()
+            })
+            .collect::<HintedStrResult<Vec<_>>>()
+            .at(span)?;
+
+        let cell = |body: Content| {
+            TableItem::Cell(Packed::new(TableCell::new(body)).spanned(span))
+        };
+        let mut children = vec![];
+        if header {
+            let cells = keys.iter().map(|key| cell(key.clone().into_value().display()));
+            let header = Packed::new(TableHeader::new(cells.collect())).spanned(span);
+            children.push(TableChild::Header(header));
+        }
+
+        let mut numeric = vec![true; keys.len()];
+        for row in &data {
+            for (i, key) in keys.iter().enumerate() {
+                let Ok(value) = row.get(key) else {
+                    children.push(TableChild::Item(cell(Content::empty())));
+                    continue;
+                };
+
+                numeric[i] &= is_numeric(value).unwrap_or(true);
+
+                let body = match &formatters[i] {
+                    Some(func) => func.call(engine, context, [value.clone()])?.display(),
+                    None => value.clone().display(),
+                };
+                children.push(TableChild::Item(cell(body)));
+            }
+        }
+
+        let mut table = TableElem::new(children)
+            .with_columns(TrackSizings(smallvec![Sizing::Auto; keys.len()]));
+        if let Smart::Custom(align) = align {
+            table = table.with_align(align);
+        } else if !data.is_empty() && numeric.contains(&true) {
+            table = table.with_align(Celled::Array(
+                numeric
+                    .into_iter()
+                    .map(
+                        |n| if n { Smart::Custom(Alignment::RIGHT) } else { Smart::Auto },
+                    )
+                    .collect(),
+            ));
+        }
+
+        Ok(table.pack().spanned(span))
+    }
+}
+
+/// Whether a value is a number for the purposes of alignment inference in
+/// [`TableElem::from`]. Strings count if they hold a number, since data loaded
+/// from CSV consists of strings only.
+///
+/// Returns `None` for empty values, which don't affect the inference.
+fn is_numeric(value: &Value) -> Option<bool> {
+    match value {
+        Value::None => None,
+        Value::Int(_) | Value::Float(_) | Value::Decimal(_) => Some(true),
+        Value::Str(s) => {
+            let s = s.trim();
+            if s.is_empty() {
+                return None;
+            }
+            // Require a digit so that words like "inf" are not treated as
+            // numbers.
+            Some(s.parse::<f64>().is_ok() && s.contains(|c: char| c.is_ascii_digit()))
+        }
+        _ => Some(false),
+    }
 }
 
 impl Synthesize for Packed<TableElem> {
@@ -769,13 +917,33 @@ pub struct TableCell {
     /// while a cell spanning at least one `{auto}`-sized row is breakable.
     pub breakable: Smart<bool>,
 
+    /// Whether this cell is a repetition of a header or footer cell in a
+    /// later region. This is determined during layout and cannot be set
+    /// manually. It is thus only available on cells that are being laid out,
+    /// for instance in show rules.
+    ///
+    /// Together with a show rule, this can be used to mark tables that
+    /// continue on the next page.
+    ///
+    /// ```example
+    /// #set page(height: 7em)
+    /// #show table.cell.where(is-repeated: true): it => {
+    ///   set text(gray)
+    ///   it
+    ///   [(continued)]
+    /// }
+    ///
+    /// #table(
+    ///   table.header[*Fruit*],
+    ///   [Apple], [Banana], [Cherry],
+    /// )
+    /// ```
+    #[synthesized]
+    pub is_repeated: bool,
+
     #[internal]
     #[parse(Some(Smart::Auto))]
     pub kind: Smart<TableCellKind>,
-
-    #[internal]
-    #[parse(Some(false))]
-    pub is_repeated: bool,
 }
 
 cast! {
@@ -12,7 +12,7 @@ use crate::foundations::{
     Styles, Value,
 };
 use crate::introspection::{Introspector, Locator, SplitLocator};
-use crate::layout::{Frame, Region};
+use crate::layout::{Fragment, Frame, Region, Regions};
 use crate::model::DocumentInfo;
 use crate::visualize::Color;
 use crate::{Library, World};
@@ -97,6 +97,15 @@ routines! {
         region: Region,
     ) -> SourceResult<Frame>
 
+    /// Lays out content into multiple regions, producing one frame per region.
+    fn layout_fragment(
+        engine: &mut Engine,
+        content: &Content,
+        locator: Locator,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment>
+
     /// Constructs the `html` module.
     fn html_module() -> Module
 
@@ -1,8 +1,12 @@
 use crate::foundations::{Cast, Content, Str, cast, func};
-use crate::text::TextElem;
+use crate::text::{Lang, TextElem};
 
 /// Converts a string or content to lowercase.
 ///
+/// When applied to content, the conversion takes the @text.lang[text language]
+/// into account. For instance, Turkish text keeps the distinction between
+/// dotted and dotless i.
+///
 /// = Example <example>
 /// ```example
 /// #lower("ABC") \
@@ -19,6 +23,9 @@ pub fn lower(
 
 /// Converts a string or content to uppercase.
 ///
+/// Like with @lower[`lower`], the conversion of content takes the
+/// @text.lang[text language] into account.
+///
 /// = Example <example>
 /// ```example
 /// #upper("abc") \
@@ -36,7 +43,7 @@ pub fn upper(
 /// Change the case of text.
 fn case(text: Caseable, case: Case) -> Caseable {
     match text {
-        Caseable::Str(v) => Caseable::Str(case.apply(&v).into()),
+        Caseable::Str(v) => Caseable::Str(case.apply(&v, None).into()),
         Caseable::Content(v) => Caseable::Content(v.set(TextElem::case, Some(case))),
     }
 }
@@ -67,11 +74,41 @@ pub enum Case {
 }
 
 impl Case {
-    /// Apply the case to a string.
-    pub fn apply(self, text: &str) -> String {
+    /// Apply the case to a string, following the conventions of the given
+    /// language if any.
+    pub fn apply(self, text: &str, lang: Option<Lang>) -> String {
+        // Turkic languages pair dotted and dotless i in both cases.
+        if lang.is_some_and(|lang| lang == Lang::TURKISH || lang == Lang::AZERBAIJANI) {
+            let mut out = String::with_capacity(text.len());
+            for c in text.chars() {
+                match (self, c) {
+                    (Self::Lower, 'I') => out.push('ı'),
+                    (Self::Lower, 'İ') => out.push('i'),
+                    (Self::Upper, 'i') => out.push('İ'),
+                    (Self::Lower, c) => out.extend(c.to_lowercase()),
+                    (Self::Upper, c) => out.extend(c.to_uppercase()),
+                }
+            }
+            return out;
+        }
+
         match self {
             Self::Lower => text.to_lowercase(),
             Self::Upper => text.to_uppercase(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_turkic() {
+        assert_eq!(Case::Upper.apply("istanbul", None), "ISTANBUL");
+        assert_eq!(Case::Upper.apply("istanbul", Some(Lang::TURKISH)), "İSTANBUL");
+        assert_eq!(Case::Lower.apply("ILIK", Some(Lang::TURKISH)), "ılık");
+        assert_eq!(Case::Lower.apply("İzmir", Some(Lang::AZERBAIJANI)), "izmir");
+        assert_eq!(Case::Lower.apply("ILIK", Some(Lang::ENGLISH)), "ilik");
+    }
+}
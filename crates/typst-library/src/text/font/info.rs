@@ -3,7 +3,7 @@ use std::fmt::{self, Debug, Formatter};
 use serde::{Deserialize, Serialize};
 use ttf_parser::{PlatformId, name_id};
 
-use super::find_exception;
+use super::{find_exception, woff};
 use crate::text::{
     AxisValue, FontAxis, FontStretch, FontStyle, FontVariant, FontWeight, Tag,
 };
@@ -45,6 +45,10 @@ bitflags::bitflags! {
 impl FontInfo {
     /// Compute metadata for font at the `index` of the given data.
     pub fn new(data: &[u8], index: u32) -> Option<Self> {
+        if woff::is_woff(data) {
+            return Self::new(&woff::decode(data)?, index);
+        }
+
         let ttf = ttf_parser::Face::parse(data, index).ok()?;
         Self::from_ttf(&ttf)
     }
@@ -9,6 +9,7 @@ mod metrics;
 mod tag;
 mod variant;
 mod variations;
+mod woff;
 
 pub use self::book::FontBook;
 pub use self::info::{Coverage, FontFlags, FontInfo};
@@ -60,7 +61,11 @@ struct FontInner {
 
 impl Font {
     /// Parse a font from data and collection index.
+    ///
+    /// Fonts wrapped in WOFF 1.0 are unwrapped first.
     pub fn new(data: Bytes, index: u32) -> Option<Self> {
+        let data = unwrap_woff(data)?;
+
         // Safety:
         // - The slices's location is stable in memory:
         //   - We don't move the underlying vector
@@ -78,8 +83,11 @@ impl Font {
 
     /// Parse all fonts in the given data.
     pub fn iter(data: Bytes) -> impl Iterator<Item = Self> {
-        let count = ttf_parser::fonts_in_collection(&data).unwrap_or(1);
-        (0..count).filter_map(move |index| Self::new(data.clone(), index))
+        let data = unwrap_woff(data);
+        let count = data
+            .as_deref()
+            .map_or(0, |data| ttf_parser::fonts_in_collection(data).unwrap_or(1));
+        (0..count).filter_map(move |index| Self::new(data.clone()?, index))
     }
 
     /// The underlying buffer.
@@ -144,6 +152,11 @@ impl Font {
     }
 }
 
+/// Unwraps WOFF-wrapped font data and leaves other data untouched.
+fn unwrap_woff(data: Bytes) -> Option<Bytes> {
+    if woff::is_woff(&data) { woff::decode(&data).map(Bytes::new) } else { Some(data) }
+}
+
 impl Debug for Font {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "Font({}, {:?})", self.info().family, self.info().variant)
@@ -0,0 +1,187 @@
+//! Decoding of fonts wrapped in the Web Open Font Format (WOFF).
+
+use std::io::Read;
+
+/// The signature at the start of a WOFF 1.0 file.
+const SIGNATURE: &[u8] = b"wOFF";
+
+/// The size of the WOFF header in bytes.
+const HEADER_SIZE: usize = 44;
+
+/// The size of a WOFF table directory entry in bytes.
+const ENTRY_SIZE: usize = 20;
+
+/// The maximum size of the decoded font in bytes.
+///
+/// The decoded table lengths are declared by the file itself. Without a limit,
+/// a small file could make us decompress and allocate gigabytes.
+const MAX_SFNT_SIZE: usize = 64 * 1024 * 1024;
+
+/// Whether the data is a WOFF 1.0 file.
+pub fn is_woff(data: &[u8]) -> bool {
+    data.starts_with(SIGNATURE)
+}
+
+/// Unwraps a WOFF 1.0 file into the TrueType or OpenType data it contains.
+///
+/// Returns `None` if the data is malformed.
+pub fn decode(data: &[u8]) -> Option<Vec<u8>> {
+    if !is_woff(data) {
+        return None;
+    }
+
+    let flavor = read_u32(data, 4)?;
+    let num_tables = read_u16(data, 12)?;
+    if num_tables == 0 {
+        return None;
+    }
+
+    // Read the table directory and check the decoded size before
+    // decompressing anything.
+    let mut entries = Vec::with_capacity(num_tables.into());
+    let mut total = 12 + 16 * usize::from(num_tables);
+    for i in 0..usize::from(num_tables) {
+        let entry = HEADER_SIZE + i * ENTRY_SIZE;
+        let orig_length = read_u32(data, entry + 12)? as usize;
+        entries.push((
+            read_u32(data, entry)?,
+            read_u32(data, entry + 4)? as usize,
+            read_u32(data, entry + 8)? as usize,
+            orig_length,
+            read_u32(data, entry + 16)?,
+        ));
+        total = total.checked_add(orig_length.checked_next_multiple_of(4)?)?;
+        if total > MAX_SFNT_SIZE {
+            return None;
+        }
+    }
+
+    // Write the offset table of the SFNT.
+    let entry_selector = (u16::BITS - 1 - num_tables.leading_zeros()) as u16;
+    let search_range = (1_u16 << entry_selector).checked_mul(16)?;
+    let range_shift = num_tables.checked_mul(16)? - search_range;
+
+    let mut out = Vec::with_capacity(total);
+    out.extend_from_slice(&flavor.to_be_bytes());
+    out.extend_from_slice(&num_tables.to_be_bytes());
+    out.extend_from_slice(&search_range.to_be_bytes());
+    out.extend_from_slice(&entry_selector.to_be_bytes());
+    out.extend_from_slice(&range_shift.to_be_bytes());
+
+    // Reserve space for the table records, which are filled in as the tables
+    // are written.
+    let records = out.len();
+    out.resize(records + 16 * usize::from(num_tables), 0);
+
+    for (i, (tag, offset, comp_length, orig_length, checksum)) in
+        entries.into_iter().enumerate()
+    {
+        let compressed = data.get(offset..offset.checked_add(comp_length)?)?;
+        let start = out.len();
+        if comp_length < orig_length {
+            flate2::read::ZlibDecoder::new(compressed)
+                .take(orig_length as u64)
+                .read_to_end(&mut out)
+                .ok()?;
+            if out.len() - start != orig_length {
+                return None;
+            }
+        } else if comp_length == orig_length {
+            out.extend_from_slice(compressed);
+        } else {
+            return None;
+        }
+
+        // Tables are aligned to four bytes.
+        out.resize(out.len().next_multiple_of(4), 0);
+
+        let record = records + i * 16;
+        out[record..record + 4].copy_from_slice(&tag.to_be_bytes());
+        out[record + 4..record + 8].copy_from_slice(&checksum.to_be_bytes());
+        out[record + 8..record + 12]
+            .copy_from_slice(&u32::try_from(start).ok()?.to_be_bytes());
+        out[record + 12..record + 16]
+            .copy_from_slice(&u32::try_from(orig_length).ok()?.to_be_bytes());
+    }
+
+    Some(out)
+}
+
+/// Reads a big-endian `u16` at the given offset.
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(data.get(offset..offset + 2)?.try_into().ok()?))
+}
+
+/// Reads a big-endian `u32` at the given offset.
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wraps uncompressed tables into a minimal WOFF file.
+    fn woff(tables: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let mut data = vec![0; HEADER_SIZE];
+        data[..4].copy_from_slice(SIGNATURE);
+        data[4..8].copy_from_slice(&0x00010000_u32.to_be_bytes());
+        data[12..14].copy_from_slice(&(tables.len() as u16).to_be_bytes());
+
+        let mut offset = HEADER_SIZE + tables.len() * ENTRY_SIZE;
+        for (tag, table) in tables {
+            data.extend_from_slice(*tag);
+            data.extend_from_slice(&(offset as u32).to_be_bytes());
+            data.extend_from_slice(&(table.len() as u32).to_be_bytes());
+            data.extend_from_slice(&(table.len() as u32).to_be_bytes());
+            data.extend_from_slice(&0_u32.to_be_bytes());
+            offset += table.len();
+        }
+
+        for (_, table) in tables {
+            data.extend_from_slice(table);
+        }
+
+        data
+    }
+
+    #[test]
+    fn test_woff_decode() {
+        let data = woff(&[(b"abcd", b"12345"), (b"efgh", b"678")]);
+        let sfnt = decode(&data).unwrap();
+        assert_eq!(read_u32(&sfnt, 0), Some(0x00010000));
+        assert_eq!(read_u16(&sfnt, 4), Some(2));
+        assert_eq!(read_u16(&sfnt, 6), Some(32));
+        assert_eq!(read_u16(&sfnt, 8), Some(1));
+        assert_eq!(read_u16(&sfnt, 10), Some(0));
+
+        // The first table starts after the offset table and the two records
+        // and is padded to four bytes.
+        assert_eq!(&sfnt[12..16], b"abcd");
+        assert_eq!(read_u32(&sfnt, 20), Some(44));
+        assert_eq!(read_u32(&sfnt, 24), Some(5));
+        assert_eq!(&sfnt[44..49], b"12345");
+        assert_eq!(read_u32(&sfnt, 36), Some(52));
+        assert_eq!(&sfnt[52..55], b"678");
+        assert_eq!(sfnt.len(), 56);
+    }
+
+    #[test]
+    fn test_woff_decode_malformed() {
+        assert_eq!(decode(b"wOFF"), None);
+        let mut data = woff(&[(b"abcd", b"12345")]);
+        data.truncate(data.len() - 1);
+        assert_eq!(decode(&data), None);
+    }
+
+    #[test]
+    fn test_woff_decode_too_large() {
+        // A table that claims to inflate to more than the limit is rejected
+        // before any decompression happens.
+        let mut data = woff(&[(b"abcd", b"12345")]);
+        let orig_length = HEADER_SIZE + 12;
+        data[orig_length..orig_length + 4]
+            .copy_from_slice(&(MAX_SFNT_SIZE as u32).to_be_bytes());
+        assert_eq!(decode(&data), None);
+    }
+}
@@ -5,8 +5,10 @@ use ecow::EcoString;
 use typst_syntax::Span;
 
 use crate::layout::{Abs, Em, Point, Rect};
-use crate::text::{FontInstance, Lang, Region, is_default_ignorable};
-use crate::visualize::{FixedStroke, Paint};
+use crate::text::{
+    Font, FontInstance, FontVariant, FontVariations, Lang, Region, is_default_ignorable,
+};
+use crate::visualize::{Color, FixedStroke, Paint};
 
 /// A run of shaped text.
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -31,6 +33,28 @@ pub struct TextItem {
 }
 
 impl TextItem {
+    /// Creates a black, unstroked English text run in the font's default
+    /// variant.
+    ///
+    /// This is meant for building frames by hand in tests.
+    #[doc(hidden)]
+    pub fn plain(font: &Font, size: Abs, text: EcoString, glyphs: Vec<Glyph>) -> Self {
+        Self {
+            font: font.clone().instantiate(
+                FontVariant::default(),
+                size,
+                &FontVariations::default(),
+            ),
+            size,
+            fill: Color::BLACK.into(),
+            stroke: None,
+            lang: Lang::ENGLISH,
+            region: None,
+            text,
+            glyphs,
+        }
+    }
+
     /// The width of the text run.
     pub fn width(&self) -> Abs {
         self.glyphs.iter().map(|g| g.x_advance).sum::<Em>().at(self.size)
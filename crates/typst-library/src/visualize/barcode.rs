@@ -0,0 +1,241 @@
+use ecow::EcoString;
+
+use crate::diag::{StrResult, bail};
+use crate::foundations::{Cast, elem};
+use crate::layout::{Abs, Length};
+use crate::visualize::{Color, Paint};
+
+/// A one-dimensional barcode.
+///
+/// The barcode is drawn with vector shapes, so it stays crisp at any zoom
+/// level and print resolution. It does not include a quiet zone, so make sure
+/// to leave some empty space around it for scanners to pick it up reliably.
+///
+/// = Example <example>
+/// ```example
+/// #barcode("4006381333931")
+///
+/// #barcode(
+///   kind: "code-128",
+///   height: 24pt,
+///   "Typst",
+/// )
+/// ```
+#[elem]
+pub struct BarcodeElem {
+    /// The data to encode.
+    ///
+    /// For EAN-13, this must consist of 12 or 13 digits. If 12 digits are
+    /// given, the check digit is computed automatically. Otherwise, it is
+    /// validated.
+    ///
+    /// For Code 128, this may contain any printable ASCII characters.
+    #[required]
+    pub data: EcoString,
+
+    /// The barcode symbology to use.
+    #[default(BarcodeKind::Ean13)]
+    pub kind: BarcodeKind,
+
+    /// The width of a single module, that is, of the narrowest bar or space.
+    ///
+    /// Must be positive.
+    #[default(Abs::pt(1.0).into())]
+    pub module: Length,
+
+    /// The height of the bars.
+    ///
+    /// Must be positive.
+    #[default(Abs::pt(40.0).into())]
+    pub height: Length,
+
+    /// How to fill the bars.
+    #[default(Color::BLACK.into())]
+    pub fill: Paint,
+}
+
+/// A barcode symbology.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum BarcodeKind {
+    /// The EAN-13 symbology used for retail products.
+    #[string("ean-13")]
+    Ean13,
+    /// The Code 128 symbology, which can encode all printable ASCII
+    /// characters.
+    #[string("code-128")]
+    Code128,
+}
+
+impl BarcodeKind {
+    /// Encodes the data into a sequence of modules, where `true` denotes a
+    /// bar and `false` a space.
+    pub fn encode(self, data: &str) -> StrResult<Vec<bool>> {
+        match self {
+            Self::Ean13 => encode_ean13(data),
+            Self::Code128 => encode_code128(data),
+        }
+    }
+}
+
+/// The left-hand odd parity (L) patterns of EAN-13. The right-hand (R)
+/// patterns are their complement and the even parity (G) patterns are the
+/// reversed R patterns.
+const EAN_L: [u8; 10] = [
+    0b0001101, 0b0011001, 0b0010011, 0b0111101, 0b0100011, 0b0110001, 0b0101111,
+    0b0111011, 0b0110111, 0b0001011,
+];
+
+/// For each leading EAN-13 digit, which of the six left-hand digits use even
+/// parity (G) patterns, starting from the most significant bit.
+const EAN_PARITY: [u8; 10] = [
+    0b000000, 0b001011, 0b001101, 0b001110, 0b010011, 0b011001, 0b011100, 0b010101,
+    0b010110, 0b011010,
+];
+
+/// Encodes EAN-13 data.
+fn encode_ean13(data: &str) -> StrResult<Vec<bool>> {
+    let mut digits = data
+        .chars()
+        .map(|c| c.to_digit(10).map(|d| d as u8))
+        .collect::<Option<Vec<_>>>()
+        .ok_or("EAN-13 data must only contain digits")?;
+
+    let check = ean13_check_digit(&digits[..digits.len().min(12)]);
+    match digits.len() {
+        12 => digits.push(check),
+        13 if digits[12] == check => {}
+        13 => bail!("invalid EAN-13 check digit (expected {check})"),
+        n => bail!("EAN-13 data must consist of 12 or 13 digits, found {n}"),
+    }
+
+    let mut modules = Vec::with_capacity(95);
+    let mut push = |bits: u8, len: u32| {
+        modules.extend((0..len).rev().map(|i| bits & (1 << i) != 0));
+    };
+
+    push(0b101, 3);
+    let parity = EAN_PARITY[digits[0] as usize];
+    for (i, &digit) in digits[1..7].iter().enumerate() {
+        let l = EAN_L[digit as usize];
+        let even = parity & (1 << (5 - i)) != 0;
+        push(if even { (!l.reverse_bits() >> 1) & 0x7F } else { l }, 7);
+    }
+    push(0b01010, 5);
+    for &digit in &digits[7..] {
+        push(!EAN_L[digit as usize] & 0x7F, 7);
+    }
+    push(0b101, 3);
+
+    Ok(modules)
+}
+
+/// Computes the EAN-13 check digit for the first twelve digits.
+fn ean13_check_digit(digits: &[u8]) -> u8 {
+    let sum: u32 = digits
+        .iter()
+        .enumerate()
+        .map(|(i, &d)| d as u32 * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    ((10 - sum % 10) % 10) as u8
+}
+
+/// The Code 128 symbols as alternating bar and space widths, in modules.
+const CODE128: [&str; 107] = [
+    "212222", "222122", "222221", "121223", "121322", "131222", "122213", "122312",
+    "132212", "221213", "221312", "231212", "112232", "122132", "122231", "113222",
+    "123122", "123221", "223211", "221132", "221231", "213212", "223112", "312131",
+    "311222", "321122", "321221", "312212", "322112", "322211", "212123", "212321",
+    "232121", "111323", "131123", "131321", "112313", "132113", "132311", "211313",
+    "231113", "231311", "112133", "112331", "132131", "113123", "113321", "133121",
+    "313121", "211331", "231131", "213113", "213311", "213131", "311123", "311321",
+    "331121", "312113", "312311", "332111", "314111", "221411", "431111", "111224",
+    "111422", "121124", "121421", "141122", "141221", "112214", "112412", "122114",
+    "122411", "142112", "142211", "241211", "221114", "413111", "241112", "134111",
+    "111242", "121142", "121241", "114212", "124112", "124211", "411212", "421112",
+    "421211", "212141", "214121", "412121", "111143", "111341", "131141", "114113",
+    "114311", "411113", "411311", "113141", "114131", "311141", "411131", "211412",
+    "211214", "211232", "2331112",
+];
+
+/// The Code 128 symbol that starts code set B.
+const CODE128_START_B: usize = 104;
+
+/// The Code 128 stop symbol.
+const CODE128_STOP: usize = 106;
+
+/// Encodes Code 128 data using code set B.
+fn encode_code128(data: &str) -> StrResult<Vec<bool>> {
+    if data.is_empty() {
+        bail!("Code 128 data must not be empty");
+    }
+
+    let mut symbols = vec![CODE128_START_B];
+    for c in data.chars() {
+        if !(' '..='~').contains(&c) {
+            bail!("Code 128 data must only contain printable ASCII characters");
+        }
+        symbols.push(c as usize - ' ' as usize);
+    }
+
+    let checksum = symbols
+        .iter()
+        .enumerate()
+        .map(|(i, &symbol)| i.max(1) * symbol)
+        .sum::<usize>()
+        % 103;
+    symbols.push(checksum);
+    symbols.push(CODE128_STOP);
+
+    let mut modules = vec![];
+    for symbol in symbols {
+        for (i, width) in CODE128[symbol].bytes().enumerate() {
+            let bar = i % 2 == 0;
+            modules.extend(std::iter::repeat_n(bar, (width - b'0') as usize));
+        }
+    }
+
+    Ok(modules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn test(kind: BarcodeKind, data: &str, expected: &str) {
+        let modules: String = kind
+            .encode(data)
+            .unwrap()
+            .iter()
+            .map(|&bar| if bar { '1' } else { '0' })
+            .collect();
+        assert_eq!(modules, expected);
+    }
+
+    #[test]
+    fn test_barcode_encode_ean13() {
+        // Guards, left half with LGLLGG parity for the leading 4, center guard,
+        // right half, guard.
+        let expected = "101\
+                        000110101001110101111011110100010010110011\
+                        01010\
+                        100001010000101000010111010010000101100110\
+                        101";
+        test(BarcodeKind::Ean13, "4006381333931", expected);
+        test(BarcodeKind::Ean13, "400638133393", expected);
+    }
+
+    #[test]
+    fn test_barcode_encode_code128() {
+        // Start B, "H", "i", checksum 84, stop.
+        test(
+            BarcodeKind::Code128,
+            "Hi",
+            "11010010000\
+             11000101000\
+             10000110100\
+             10011110100\
+             1100011101011",
+        );
+    }
+}
@@ -1,5 +1,6 @@
 //! Drawing and visualization.
 
+mod barcode;
 mod color;
 mod curve;
 mod gradient;
@@ -7,10 +8,12 @@ mod image;
 mod line;
 mod paint;
 mod polygon;
+mod qrcode;
 mod shape;
 mod stroke;
 mod tiling;
 
+pub use self::barcode::*;
 pub use self::color::*;
 pub use self::curve::*;
 pub use self::gradient::*;
@@ -18,6 +21,7 @@ pub use self::image::*;
 pub use self::line::*;
 pub use self::paint::*;
 pub use self::polygon::*;
+pub use self::qrcode::*;
 pub use self::shape::*;
 pub use self::stroke::*;
 pub use self::tiling::*;
@@ -39,5 +43,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<CircleElem>();
     global.define_elem::<PolygonElem>();
     global.define_elem::<CurveElem>();
+    global.define_elem::<BarcodeElem>();
+    global.define_elem::<QrCodeElem>();
     global.reset_category();
 }
@@ -0,0 +1,651 @@
+use ecow::EcoString;
+
+use crate::diag::{StrResult, bail};
+use crate::foundations::{Cast, elem};
+use crate::layout::{Abs, Length};
+use crate::visualize::{Color, Paint};
+
+/// A two-dimensional QR code.
+///
+/// The data is encoded as UTF-8 bytes and the smallest symbol version that
+/// fits it at the requested error correction level is picked automatically.
+/// Like the @barcode, the QR code is drawn with vector shapes and does not
+/// include a quiet zone. Scanners expect four modules of empty space around
+/// the symbol.
+///
+/// = Example <example>
+/// ```example
+/// #qrcode("https://typst.app")
+///
+/// #qrcode(
+///   level: "h",
+///   module: 1.5pt,
+///   "Hello, world!",
+/// )
+/// ```
+#[elem(name = "qrcode", title = "QR Code")]
+pub struct QrCodeElem {
+    /// The data to encode.
+    #[required]
+    pub data: EcoString,
+
+    /// How much error correction to add.
+    ///
+    /// Higher levels let scanners recover from more damage, at the cost of a
+    /// larger symbol.
+    #[default(QrLevel::M)]
+    pub level: QrLevel,
+
+    /// The width and height of a single module.
+    ///
+    /// Must be positive.
+    #[default(Abs::pt(2.0).into())]
+    pub module: Length,
+
+    /// How to fill the dark modules.
+    #[default(Color::BLACK.into())]
+    pub fill: Paint,
+}
+
+/// A QR code error correction level.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum QrLevel {
+    /// Recovers about 7% of the codewords.
+    L,
+    /// Recovers about 15% of the codewords.
+    M,
+    /// Recovers about 25% of the codewords.
+    Q,
+    /// Recovers about 30% of the codewords.
+    H,
+}
+
+impl QrLevel {
+    /// The index of the level in the capacity tables.
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The two bits identifying the level in the format information.
+    fn format_bits(self) -> u32 {
+        match self {
+            Self::L => 1,
+            Self::M => 0,
+            Self::Q => 3,
+            Self::H => 2,
+        }
+    }
+}
+
+/// An encoded QR code symbol.
+pub struct QrCode {
+    /// The number of modules along each side.
+    size: usize,
+    /// Whether each module is dark, in row-major order.
+    modules: Vec<bool>,
+    /// Whether each module belongs to a function pattern and is thus exempt
+    /// from data placement and masking.
+    function: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encodes the data in byte mode, using the smallest version that fits.
+    pub fn encode(data: &[u8], level: QrLevel) -> StrResult<Self> {
+        let Some(version) = (1..=40).find(|&version| {
+            let bits = 4 + char_count_bits(version) + 8 * data.len();
+            bits <= data_codewords(version, level) * 8
+        }) else {
+            bail!("data is too long for a QR code ({} bytes)", data.len());
+        };
+
+        let codewords =
+            add_error_correction(&data_bits(data, version, level), version, level);
+
+        let size = version * 4 + 17;
+        let mut qr = Self {
+            size,
+            modules: vec![false; size * size],
+            function: vec![false; size * size],
+        };
+
+        qr.draw_function_patterns(version);
+        qr.draw_codewords(&codewords);
+
+        // Pick the mask with the lowest penalty. Masking twice is a no-op, so
+        // each candidate is undone after scoring it.
+        let mask = (0..8)
+            .min_by_key(|&mask| {
+                qr.apply_mask(mask);
+                qr.draw_format_bits(level, mask);
+                let penalty = qr.penalty();
+                qr.apply_mask(mask);
+                penalty
+            })
+            .unwrap();
+
+        qr.apply_mask(mask);
+        qr.draw_format_bits(level, mask);
+
+        Ok(qr)
+    }
+
+    /// The number of modules along each side.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the module at the given column and row is dark.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    /// Sets a module and marks it as part of a function pattern.
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        self.function[y * self.size + x] = true;
+    }
+
+    /// Draws the timing, finder, and alignment patterns as well as the
+    /// version information. The format bits are reserved with a dummy value.
+    fn draw_function_patterns(&mut self, version: usize) {
+        let size = self.size;
+
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        // The finder patterns, including their separators.
+        for (cx, cy) in [(3, 3), (size - 4, 3), (3, size - 4)] {
+            for dy in -4..=4_isize {
+                for dx in -4..=4_isize {
+                    let (Some(x), Some(y)) =
+                        (cx.checked_add_signed(dx), cy.checked_add_signed(dy))
+                    else {
+                        continue;
+                    };
+                    if x < size && y < size {
+                        let dist = dx.abs().max(dy.abs());
+                        self.set_function(x, y, dist != 2 && dist != 4);
+                    }
+                }
+            }
+        }
+
+        // The alignment patterns, except where they would overlap the finder
+        // patterns.
+        let positions = alignment_positions(version);
+        let last = positions.len().saturating_sub(1);
+        for (i, &cx) in positions.iter().enumerate() {
+            for (j, &cy) in positions.iter().enumerate() {
+                if (i == 0 && j == 0) || (i == 0 && j == last) || (i == last && j == 0) {
+                    continue;
+                }
+                for dy in -2..=2_isize {
+                    for dx in -2..=2_isize {
+                        let x = cx.wrapping_add_signed(dx);
+                        let y = cy.wrapping_add_signed(dy);
+                        self.set_function(x, y, dx.abs().max(dy.abs()) != 1);
+                    }
+                }
+            }
+        }
+
+        self.draw_format_bits(QrLevel::M, 0);
+
+        if version >= 7 {
+            let mut rem = version as u32;
+            for _ in 0..12 {
+                rem = (rem << 1) ^ ((rem >> 11) * 0x1F25);
+            }
+            let bits = (version as u32) << 12 | rem;
+            for i in 0..18 {
+                let dark = bits & (1 << i) != 0;
+                let a = size - 11 + i % 3;
+                let b = i / 3;
+                self.set_function(a, b, dark);
+                self.set_function(b, a, dark);
+            }
+        }
+    }
+
+    /// Draws both copies of the format information and the dark module.
+    fn draw_format_bits(&mut self, level: QrLevel, mask: u8) {
+        let size = self.size;
+        let bits = format_bits(level, mask);
+        let bit = |i: usize| bits & (1 << i) != 0;
+
+        for i in 0..6 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        self.set_function(8, size - 8, true);
+    }
+
+    /// Places the codewords in the zigzag pattern of two-module wide columns,
+    /// skipping the function patterns.
+    fn draw_codewords(&mut self, codewords: &[u8]) {
+        let size = self.size;
+        let mut i = 0;
+        let mut right = size - 1;
+        loop {
+            // Skip the vertical timing pattern.
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vert in 0..size {
+                let y = if upward { size - 1 - vert } else { vert };
+                for x in [right, right - 1] {
+                    let index = y * size + x;
+                    if !self.function[index] && i < codewords.len() * 8 {
+                        self.modules[index] = codewords[i / 8] & (0x80 >> (i % 8)) != 0;
+                        i += 1;
+                    }
+                }
+            }
+            if right < 3 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    /// Inverts the data modules selected by the mask pattern.
+    fn apply_mask(&mut self, mask: u8) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let index = y * self.size + x;
+                if invert && !self.function[index] {
+                    self.modules[index] ^= true;
+                }
+            }
+        }
+    }
+
+    /// Scores the symbol according to the mask evaluation rules of the
+    /// specification. Lower is better.
+    fn penalty(&self) -> usize {
+        let size = self.size;
+        let mut penalty = 0;
+
+        // Runs of same-colored modules and finder-like patterns, in rows and
+        // columns.
+        for transpose in [false, true] {
+            for a in 0..size {
+                let get = |b| if transpose { self.get(a, b) } else { self.get(b, a) };
+                let mut history = RunHistory::new(size);
+                let mut color = false;
+                let mut run = 0;
+                for b in 0..size {
+                    if get(b) == color {
+                        run += 1;
+                        if run == 5 {
+                            penalty += 3;
+                        } else if run > 5 {
+                            penalty += 1;
+                        }
+                    } else {
+                        history.push(run);
+                        if !color {
+                            penalty += history.finder_patterns() * 40;
+                        }
+                        color = get(b);
+                        run = 1;
+                    }
+                }
+                penalty += history.terminate(color, run) * 40;
+            }
+        }
+
+        // Blocks of 2x2 same-colored modules.
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let color = self.get(x, y);
+                if color == self.get(x + 1, y)
+                    && color == self.get(x, y + 1)
+                    && color == self.get(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        // Deviation of the dark module ratio from 50%, in steps of 5%.
+        let dark = self.modules.iter().filter(|&&dark| dark).count();
+        let total = size * size;
+        let k =
+            (((dark * 20).abs_diff(total * 10) + total - 1) / total).saturating_sub(1);
+        penalty + k * 10
+    }
+}
+
+/// The lengths of the most recent runs in a row or column, used to detect
+/// patterns that look like finder patterns.
+struct RunHistory {
+    runs: [usize; 7],
+    size: usize,
+}
+
+impl RunHistory {
+    fn new(size: usize) -> Self {
+        Self { runs: [0; 7], size }
+    }
+
+    /// Records a finished run. The light border outside the symbol is added
+    /// to the first run.
+    fn push(&mut self, mut run: usize) {
+        if self.runs[0] == 0 {
+            run += self.size;
+        }
+        self.runs.copy_within(0..6, 1);
+        self.runs[0] = run;
+    }
+
+    /// Counts the 1:1:3:1:1 patterns with a light run of at least four times
+    /// the unit on either side.
+    fn finder_patterns(&self) -> usize {
+        let [r0, r1, r2, r3, r4, r5, r6] = self.runs;
+        let n = r1;
+        let core = n > 0 && r2 == n && r3 == n * 3 && r4 == n && r5 == n;
+        usize::from(core && r0 >= n * 4 && r6 >= n)
+            + usize::from(core && r6 >= n * 4 && r0 >= n)
+    }
+
+    /// Finishes the row or column, accounting for the light border at its
+    /// end.
+    fn terminate(&mut self, color: bool, mut run: usize) -> usize {
+        if color {
+            self.push(run);
+            run = 0;
+        }
+        self.push(run + self.size);
+        self.finder_patterns()
+    }
+}
+
+/// The number of error correction codewords per block, indexed by level and
+/// version.
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 40]; 4] = [
+    [
+        7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28,
+        28, 28, 30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26,
+        26, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30,
+        28, 30, 30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28,
+        30, 24, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+/// The number of error correction blocks, indexed by level and version.
+const ERROR_CORRECTION_BLOCKS: [[u8; 40]; 4] = [
+    [
+        1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12,
+        12, 13, 14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20,
+        21, 23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25,
+        27, 29, 34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30,
+        32, 35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// The number of error correction codewords per block.
+fn ecc_per_block(version: usize, level: QrLevel) -> usize {
+    ECC_CODEWORDS_PER_BLOCK[level.index()][version - 1].into()
+}
+
+/// The number of error correction blocks.
+fn num_blocks(version: usize, level: QrLevel) -> usize {
+    ERROR_CORRECTION_BLOCKS[level.index()][version - 1].into()
+}
+
+/// The number of modules available for data and error correction, including
+/// remainder bits.
+fn raw_data_modules(version: usize) -> usize {
+    let mut modules = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let alignment = version / 7 + 2;
+        modules -= (25 * alignment - 10) * alignment - 55;
+        if version >= 7 {
+            modules -= 36;
+        }
+    }
+    modules
+}
+
+/// The number of data codewords, excluding error correction.
+fn data_codewords(version: usize, level: QrLevel) -> usize {
+    raw_data_modules(version) / 8
+        - ecc_per_block(version, level) * num_blocks(version, level)
+}
+
+/// The width of the character count indicator in byte mode.
+fn char_count_bits(version: usize) -> usize {
+    if version < 10 { 8 } else { 16 }
+}
+
+/// The center coordinates of the alignment patterns along each axis.
+fn alignment_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return vec![];
+    }
+    let size = version * 4 + 17;
+    let count = version / 7 + 2;
+    let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+    let mut positions: Vec<usize> = (0..count - 1).map(|i| size - 7 - i * step).collect();
+    positions.push(6);
+    positions.reverse();
+    positions
+}
+
+/// Computes the 15 format bits for a level and mask, including their BCH
+/// error correction and the fixed XOR mask.
+fn format_bits(level: QrLevel, mask: u8) -> u32 {
+    let data = level.format_bits() << 3 | u32::from(mask);
+    let mut rem = data;
+    for _ in 0..10 {
+        rem = (rem << 1) ^ ((rem >> 9) * 0x537);
+    }
+    (data << 10 | rem) ^ 0x5412
+}
+
+/// Builds the data codewords: the byte mode segment, the terminator, and pad
+/// bytes up to the capacity of the version.
+fn data_bits(data: &[u8], version: usize, level: QrLevel) -> Vec<u8> {
+    let capacity = data_codewords(version, level) * 8;
+    let mut bits = Vec::with_capacity(capacity);
+    let mut push = |value: usize, len: usize| {
+        bits.extend((0..len).rev().map(|i| value & (1 << i) != 0));
+    };
+
+    push(0b0100, 4);
+    push(data.len(), char_count_bits(version));
+    for &byte in data {
+        push(byte.into(), 8);
+    }
+
+    let terminator = (capacity - bits.len()).min(4);
+    bits.extend(std::iter::repeat_n(false, terminator));
+    bits.resize(bits.len().next_multiple_of(8), false);
+
+    let mut bytes: Vec<u8> = bits
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0, |byte, &bit| byte << 1 | u8::from(bit)))
+        .collect();
+    for pad in [0xEC, 0x11].into_iter().cycle() {
+        if bytes.len() * 8 >= capacity {
+            break;
+        }
+        bytes.push(pad);
+    }
+
+    bytes
+}
+
+/// Splits the data codewords into blocks, appends Reed-Solomon error
+/// correction to each, and interleaves the result.
+fn add_error_correction(data: &[u8], version: usize, level: QrLevel) -> Vec<u8> {
+    let blocks = num_blocks(version, level);
+    let ecc_len = ecc_per_block(version, level);
+    let raw = raw_data_modules(version) / 8;
+    let short_blocks = blocks - raw % blocks;
+    let short_len = raw / blocks;
+    let divisor = reed_solomon_divisor(ecc_len);
+
+    let mut rest = data;
+    let mut encoded = Vec::with_capacity(blocks);
+    for i in 0..blocks {
+        let len = short_len - ecc_len + usize::from(i >= short_blocks);
+        let (block, tail) = rest.split_at(len);
+        rest = tail;
+
+        let mut codewords = block.to_vec();
+        let ecc = reed_solomon_remainder(block, &divisor);
+        // Pad short blocks so that all blocks line up during interleaving.
+        if i < short_blocks {
+            codewords.push(0);
+        }
+        codewords.extend(ecc);
+        encoded.push(codewords);
+    }
+
+    let mut result = Vec::with_capacity(raw);
+    for i in 0..short_len + 1 {
+        for (j, block) in encoded.iter().enumerate() {
+            if i != short_len - ecc_len || j >= short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+
+    result
+}
+
+/// Computes the Reed-Solomon generator polynomial of the given degree,
+/// without its leading coefficient.
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0; degree];
+    result[degree - 1] = 1;
+    let mut root = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    result
+}
+
+/// Computes the Reed-Solomon error correction codewords for a block.
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (x, &y) in result.iter_mut().zip(divisor) {
+            *x ^= gf_multiply(y, factor);
+        }
+    }
+    result
+}
+
+/// Multiplies two elements of GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1.
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= u32::from((y >> i) & 1) * u32::from(x);
+    }
+    z as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_qrcode_reed_solomon() {
+        // The "HELLO WORLD" example at level M from the specification.
+        let data =
+            [32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17];
+        let ecc = reed_solomon_remainder(&data, &reed_solomon_divisor(10));
+        assert_eq!(ecc, [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+    }
+
+    #[test]
+    fn test_qrcode_format_bits() {
+        assert_eq!(format_bits(QrLevel::M, 0), 0b101010000010010);
+        assert_eq!(format_bits(QrLevel::L, 0), 0b111011111000100);
+        assert_eq!(format_bits(QrLevel::H, 7), 0b000100000111011);
+    }
+
+    #[test]
+    fn test_qrcode_capacity() {
+        assert_eq!(data_codewords(1, QrLevel::M), 16);
+        assert_eq!(data_codewords(40, QrLevel::L), 2956);
+        assert_eq!(data_codewords(40, QrLevel::H), 1276);
+        assert_eq!(alignment_positions(32), [6, 34, 60, 86, 112, 138]);
+    }
+
+    #[test]
+    fn test_qrcode_version() {
+        // Version 1 at level M holds 14 bytes.
+        assert_eq!(QrCode::encode(&[b'a'; 14], QrLevel::M).unwrap().size(), 21);
+        assert_eq!(QrCode::encode(&[b'a'; 15], QrLevel::M).unwrap().size(), 25);
+        assert_eq!(QrCode::encode(&[b'a'; 2953], QrLevel::L).unwrap().size(), 177);
+        assert!(QrCode::encode(&[b'a'; 2954], QrLevel::L).is_err());
+    }
+
+    #[test]
+    fn test_qrcode_function_patterns() {
+        let qr = QrCode::encode(b"Hello, world!", QrLevel::M).unwrap();
+        let size = qr.size();
+        let row = |y| (0..size).map(|x| if qr.get(x, y) { '1' } else { '0' });
+        let top: String = row(0).take(8).collect();
+        let timing: String = row(6).skip(8).take(size - 16).collect();
+        assert_eq!(top, "11111110");
+        assert_eq!(timing, "10101");
+        assert!(qr.get(8, size - 8));
+    }
+}
@@ -51,6 +51,18 @@ pub fn convert(
     anchors: &[(Location, EcoString)],
     link_resolver: Option<Tracked<LateLinkResolver>>,
 ) -> SourceResult<Vec<u8>> {
+    if let Some(ppi) = options.image_max_ppi
+        && !(ppi.is_finite() && ppi > 0.0)
+    {
+        bail!(Span::detached(), "maximum image resolution must be positive and finite");
+    }
+
+    if let Some(quality) = options.image_jpeg_quality
+        && !(1..=100).contains(&quality)
+    {
+        bail!(Span::detached(), "JPEG quality must be between 1 and 100");
+    }
+
     let settings = SerializeSettings {
         compress_content_streams: !options.pretty,
         no_device_cs: true,
@@ -2,16 +2,19 @@ use std::hash::{Hash, Hasher};
 use std::sync::{Arc, OnceLock};
 
 use ecow::eco_format;
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
 use image::{DynamicImage, EncodableLayout, GenericImageView, Rgba};
 use krilla::image::{BitsPerComponent, CustomImage, ImageColorspace};
 use krilla::pdf::PdfDocument;
 use krilla::surface::Surface;
 use krilla_svg::{SurfaceExt, SvgSettings};
-use typst_library::diag::{At, SourceResult};
-use typst_library::foundations::Smart;
+use typst_library::diag::{At, SourceResult, StrResult};
+use typst_library::foundations::{Bytes, Smart};
 use typst_library::layout::{Abs, Angle, Ratio, Size, Transform};
 use typst_library::visualize::{
-    ExchangeFormat, Image, ImageKind, ImageScaling, PdfImage, RasterFormat, RasterImage,
+    ExchangeFormat, Image, ImageKind, ImageScaling, PdfImage, PixelEncoding, PixelFormat,
+    RasterFormat, RasterImage,
 };
 use typst_syntax::Span;
 use typst_utils::defer;
@@ -45,6 +48,19 @@ pub(crate) fn handle_image(
 
     match image.kind() {
         ImageKind::Raster(raster) => {
+            let dimensions = gc.options.image_max_ppi.and_then(|ppi| {
+                let placed = placed_size(size, fc.state().transform());
+                downsampled_dimensions(raster, placed, ppi)
+            });
+            let raster = &optimize_raster(
+                raster.clone(),
+                dimensions,
+                gc.options.image_jpeg_quality,
+                image.scaling() == Smart::Custom(ImageScaling::Pixelated),
+            )
+            .map_err(|err| eco_format!("failed to process image ({err})"))
+            .at(span)?;
+
             let (exif_transform, new_size) = exif_transform(raster, size);
             surface.push_transform(&exif_transform.to_krilla());
             let mut surface = defer(surface, |s| s.pop());
@@ -210,6 +226,114 @@ fn convert_raster(
     }
 }
 
+/// Determines the size at which an image of the given size ends up on the
+/// page when drawn with the given transform, e.g. when it is scaled.
+fn placed_size(size: Size, ts: Transform) -> Size {
+    // The lengths of the transformed unit vectors along both axes.
+    Size::new(
+        size.x * ts.sx.get().hypot(ts.ky.get()),
+        size.y * ts.kx.get().hypot(ts.sy.get()),
+    )
+}
+
+/// Determines the pixel dimensions to which a raster image should be
+/// downsampled so that it does not exceed the given resolution at the given
+/// placed size.
+///
+/// Returns `None` if the image's resolution is already low enough.
+fn downsampled_dimensions(
+    raster: &RasterImage,
+    size: Size,
+    ppi: f64,
+) -> Option<(u32, u32)> {
+    let (width, height) = (raster.width(), raster.height());
+
+    // Scale uniformly and keep the axis with the higher demand at the maximum
+    // resolution so that neither drops below it.
+    let factor = (ppi * size.x.to_inches() / f64::from(width))
+        .max(ppi * size.y.to_inches() / f64::from(height));
+    if !factor.is_finite() || factor >= 1.0 {
+        return None;
+    }
+
+    let scale = |v: u32| ((f64::from(v) * factor).round() as u32).max(1);
+    Some((scale(width), scale(height)))
+}
+
+/// Downsamples a raster image to the given pixel dimensions and re-encodes it
+/// as JPEG with the given quality, if requested.
+///
+/// Images with an alpha channel are never re-encoded as JPEG since it does not
+/// support transparency. The image's ICC profile is dropped if it doesn't match
+/// the color model of the optimized image, e.g. for CMYK JPEGs, which are
+/// converted to RGB when decoding. JPEG images are only re-encoded if they are also
+/// downsampled, as decoding and encoding them again would lose quality without
+/// making them much smaller. Pixelated images are downsampled with
+/// nearest-neighbor filtering to keep their hard pixel edges.
+#[comemo::memoize]
+fn optimize_raster(
+    raster: RasterImage,
+    dimensions: Option<(u32, u32)>,
+    jpeg_quality: Option<u8>,
+    pixelated: bool,
+) -> StrResult<RasterImage> {
+    let has_alpha = raster.dynamic().color().has_alpha();
+    let is_jpeg = raster.format() == RasterFormat::Exchange(ExchangeFormat::Jpg);
+    let jpeg_quality =
+        jpeg_quality.filter(|_| !has_alpha && !(is_jpeg && dimensions.is_none()));
+    if dimensions.is_none() && jpeg_quality.is_none() {
+        return Ok(raster);
+    }
+
+    let mut dynamic = raster.dynamic().as_ref().clone();
+    if let Some((width, height)) = dimensions {
+        let filter = if pixelated { FilterType::Nearest } else { FilterType::Lanczos3 };
+        dynamic = dynamic.resize_exact(width, height, filter);
+    }
+
+    let has_color = dynamic.color().has_color();
+    let icc = raster
+        .icc()
+        .filter(|icc| icc_matches(icc, has_color))
+        .cloned()
+        .map_or(Smart::Auto, Smart::Custom);
+
+    if let Some(quality) = jpeg_quality {
+        // JPEG only supports 8-bit luma and RGB.
+        let dynamic = if has_color {
+            DynamicImage::ImageRgb8(dynamic.to_rgb8())
+        } else {
+            DynamicImage::ImageLuma8(dynamic.to_luma8())
+        };
+
+        let mut buf = vec![];
+        JpegEncoder::new_with_quality(&mut buf, quality)
+            .encode_image(&dynamic)
+            .map_err(|err| eco_format!("{err}"))?;
+        return RasterImage::new(Bytes::new(buf), ExchangeFormat::Jpg, icc);
+    }
+
+    let (encoding, data) = match (has_color, has_alpha) {
+        (true, true) => (PixelEncoding::Rgba8, dynamic.to_rgba8().into_raw()),
+        (true, false) => (PixelEncoding::Rgb8, dynamic.to_rgb8().into_raw()),
+        (false, true) => (PixelEncoding::Lumaa8, dynamic.to_luma_alpha8().into_raw()),
+        (false, false) => (PixelEncoding::Luma8, dynamic.to_luma8().into_raw()),
+    };
+
+    let format = PixelFormat {
+        encoding,
+        width: dynamic.width(),
+        height: dynamic.height(),
+    };
+    RasterImage::new(Bytes::new(data), format, icc)
+}
+
+/// Whether the ICC profile's color space is RGB or grayscale, as given.
+fn icc_matches(icc: &[u8], has_color: bool) -> bool {
+    let expected: &[u8] = if has_color { b"RGB " } else { b"GRAY" };
+    icc.get(16..20) == Some(expected)
+}
+
 #[comemo::memoize]
 fn convert_pdf(pdf: &PdfImage) -> PdfDocument {
     PdfDocument::new(pdf.document().pdf().clone())
@@ -264,3 +388,92 @@ fn exif_transform(image: &RasterImage, size: Size) -> (Transform, Size) {
         _ => no_flipping(false, false),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a grayscale image with the given width from its pixels.
+    fn luma(width: u32, pixels: &[u8]) -> RasterImage {
+        let height = pixels.len() as u32 / width;
+        let format = PixelFormat { encoding: PixelEncoding::Luma8, width, height };
+        RasterImage::plain(Bytes::new(pixels.to_vec()), format).unwrap()
+    }
+
+    #[test]
+    fn test_downsampled_dimensions() {
+        let image = luma(100, &[0; 100 * 50]);
+        let inches = |x, y| Size::new(Abs::inches(x), Abs::inches(y));
+
+        // Already below the maximum resolution.
+        assert_eq!(downsampled_dimensions(&image, inches(1.0, 0.5), 300.0), None);
+        assert_eq!(downsampled_dimensions(&image, inches(1.0, 0.5), 100.0), None);
+
+        // Uniformly scaled to the maximum resolution.
+        assert_eq!(
+            downsampled_dimensions(&image, inches(1.0, 0.5), 50.0),
+            Some((50, 25))
+        );
+
+        // When distorted, the axis with the higher demand is kept at the maximum
+        // resolution.
+        assert_eq!(
+            downsampled_dimensions(&image, inches(1.0, 0.25), 50.0),
+            Some((50, 25))
+        );
+    }
+
+    #[test]
+    fn test_placed_size() {
+        let size = Size::new(Abs::pt(10.0), Abs::pt(20.0));
+        assert_eq!(placed_size(size, Transform::identity()), size);
+        assert_eq!(
+            placed_size(size, Transform::scale(Ratio::new(2.0), Ratio::new(0.5))),
+            Size::new(Abs::pt(20.0), Abs::pt(10.0)),
+        );
+
+        // Rotation and translation don't change the size.
+        let placed = placed_size(
+            size,
+            Transform::translate(Abs::pt(5.0), Abs::pt(5.0))
+                .pre_concat(Transform::rotate(Angle::deg(90.0))),
+        );
+        assert!(placed.x.approx_eq(size.x) && placed.y.approx_eq(size.y));
+    }
+
+    #[test]
+    fn test_icc_matches() {
+        let profile = |space: &[u8; 4]| [&[0; 16], space.as_slice()].concat();
+        assert!(icc_matches(&profile(b"RGB "), true));
+        assert!(icc_matches(&profile(b"GRAY"), false));
+        assert!(!icc_matches(&profile(b"CMYK"), true));
+        assert!(!icc_matches(&profile(b"RGB "), false));
+        assert!(!icc_matches(b"RGB ", true));
+    }
+
+    #[test]
+    fn test_optimize_raster_keeps_jpeg() {
+        let jpeg =
+            optimize_raster(luma(2, &[0, 255, 0, 255]), None, Some(80), false).unwrap();
+        assert_eq!(jpeg.format(), RasterFormat::Exchange(ExchangeFormat::Jpg));
+
+        // Not re-encoded again unless downsampled.
+        let same = optimize_raster(jpeg.clone(), None, Some(50), false).unwrap();
+        assert_eq!(same.data(), jpeg.data());
+        let smaller =
+            optimize_raster(jpeg.clone(), Some((1, 1)), Some(50), false).unwrap();
+        assert_eq!((smaller.width(), smaller.height()), (1, 1));
+    }
+
+    #[test]
+    fn test_optimize_raster_pixelated() {
+        let image = luma(4, &[0, 0, 255, 255]);
+        let pixels = |pixelated| {
+            let optimized = optimize_raster(image.clone(), Some((2, 1)), None, pixelated);
+            optimized.unwrap().dynamic().to_luma8().into_raw()
+        };
+
+        assert_eq!(pixels(true), [0, 255]);
+        assert_ne!(pixels(false), [0, 255]);
+    }
+}
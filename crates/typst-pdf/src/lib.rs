@@ -86,6 +86,14 @@ pub struct PdfOptions {
     pub tagged: bool,
     /// Whether to format the PDF in a human-readable way.
     pub pretty: bool,
+    /// If not `None`, raster images whose resolution at their placed size
+    /// exceeds this many pixels per inch are downsampled to it. Must be
+    /// positive and finite.
+    pub image_max_ppi: Option<f64>,
+    /// If not `None`, raster images without transparency are encoded as JPEG
+    /// with this quality, ranging from 1 to 100. Images that already are JPEGs
+    /// are only re-encoded if they are also downsampled.
+    pub image_jpeg_quality: Option<u8>,
 }
 
 impl PdfOptions {
@@ -106,6 +114,8 @@ impl Default for PdfOptions {
             standards: PdfStandards::default(),
             tagged: true,
             pretty: false,
+            image_max_ppi: None,
+            image_jpeg_quality: None,
         }
     }
 }
@@ -423,7 +423,7 @@ fn progress_tree_start(tree: &mut TreeBuilder, elem: &Content) -> GroupId {
         // times. Mark duplicate headers as artifacts, since they have no
         // semantic meaning in the tag tree, which doesn't use page breaks for
         // it's semantic structure.
-        let kind = if cell.is_repeated.val() {
+        let kind = if cell.is_repeated == Some(true) {
             GroupKind::Artifact(ArtifactType::PaginationOther)
         } else {
             let tag = tree.groups.tags.push(Tag::TD);
@@ -442,7 +442,7 @@ fn progress_tree_start(tree: &mut TreeBuilder, elem: &Content) -> GroupId {
             // If there is no grid parent, this means a grid layouter is used
             // internally.
             GroupKind::Transparent
-        } else if cell.is_repeated.val() {
+        } else if cell.is_repeated == Some(true) {
             // Only repeated grid headers and footer cells are laid out multiple
             // times. Mark duplicate headers as artifacts, since they have no
             // semantic meaning in the tag tree, which doesn't use page breaks
@@ -62,6 +62,28 @@
 // Error: 2-54 bytes must have a length of 4 or 8
 #float.from-bytes(bytes((0, 0, 0, 0, 0, 0, 0, 1, 0)))
 
+--- float-format eval ---
+#test(1234.5678.format(), "1234.5678")
+#test((-2.5).format(), "\u{2212}2.5")
+#test(1234.5678.format(digits: 3), "1230")
+#test(1234.5678.format(digits: 6), "1234.57")
+#test(0.5.format(digits: 3), "0.500")
+#test(9.996.format(digits: 3), "10.0")
+#test(1234.5678.format(digits: 3, scientific: true), "1.23 × 10³")
+#test(9.996.format(digits: 3, scientific: true), "1.00 × 10¹")
+#test(0.000123.format(scientific: true), "1.23 × 10⁻⁴")
+#test(9.81.format(uncertainty: 0.04), "9.81(4)")
+#test(1.2345.format(digits: 3, uncertainty: 0.0456), "1.23(5)")
+#test(12345.0.format(uncertainty: 400), "12300(400)")
+#test(12345.0.format(uncertainty: 400, scientific: true), "1.23(4) × 10⁴")
+#test(float.inf.format(), "∞")
+#test(1.0.format(digits: 17), "1.0000000000000000")
+#test(1.0.format(digits: 1000000000), 1.0.format(digits: 17))
+
+--- float-format-bad-uncertainty eval ---
+// Error: 2-28 uncertainty must be positive and finite
+#1.0.format(uncertainty: 0)
+
 --- float-repr eval ---
 // Test the `repr` function with floats.
 #test(repr(12.0), "12.0")
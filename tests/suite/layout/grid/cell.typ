@@ -132,6 +132,36 @@
 // Hint: 7-19 use `grid.cell` instead
 #grid(table.cell[])
 
+--- grid-cell-is-repeated-field eval ---
+// The field is only known during layout.
+#test(table.cell[A].has("is-repeated"), false)
+#test(grid.cell[A].has("is-repeated"), false)
+#test(table.cell[A].fields(), (body: [A]))
+
+--- grid-cell-is-repeated-not-settable eval ---
+// Error: 13-30 unexpected argument: is-repeated
+#table.cell(is-repeated: true)[A]
+
+--- grid-cell-is-repeated-not-set-rule eval ---
+// Error: 17-34 unexpected argument: is-repeated
+#set table.cell(is-repeated: true)
+
+--- grid-cell-is-repeated-show-rule paged empty ---
+// Repeated header cells can be targeted by a show rule. The table is invisible
+// and breaks into a second column, where its header is repeated once.
+#set page(margin: 0pt, columns: 2)
+#show table.cell.where(is-repeated: true): it => it + [#metadata(none) <repeated>]
+#let row = block(height: 5pt)
+
+#table(
+  stroke: none,
+  inset: 0pt,
+  table.header(row),
+  row, row, row, row,
+)
+
+#context test(query(<repeated>).len(), 1)
+
 --- issue-5723-grid-heading-numbering paged ---
 #set heading(numbering: "1.1.")
 #set page(width: 150pt, height: 3.5cm)
@@ -30,6 +30,43 @@
 #t(5em.abs.cm(), 0.0)
 #t((5em + 6in).abs.inches(), 6.0)
 
+--- length-constructor eval ---
+// Test parsing lengths from strings.
+#test(length(2pt), 2pt)
+#test(length("3.5cm"), 3.5cm)
+#test(length("12 pt"), 12pt)
+#test(length(" 4mm "), 4mm)
+#test(length("1in"), 1in)
+#test(length("-1.5em"), -1.5em)
+#test(length("\u{2212}2pt"), -2pt)
+#test(length("2pt + 1.5em"), 2pt + 1.5em)
+#test(length(repr(1cm + 2em)).em, 2.0)
+#test(length("2pt - 1.5em"), 2pt - 1.5em)
+#test(length("1cm-2mm+3pt"), 1cm - 2mm + 3pt)
+#test(length("1cm - -2pt"), 1cm + 2pt)
+#test(length("1e-1pt"), 0.1pt)
+#test(length(repr(1cm - 2em)), 1cm - 2em)
+
+--- length-constructor-bad-unit eval ---
+// Error: 9-15 invalid length: 3.5%
+#length("3.5%")
+
+--- length-constructor-bad-number eval ---
+// Error: 9-18 invalid length: 1.2.3pt
+#length("1.2.3pt")
+
+--- length-constructor-trailing-operator eval ---
+// Error: 9-16 invalid length: 2pt -
+#length("2pt -")
+
+--- length-constructor-missing-unit eval ---
+// Error: 9-18 invalid length: 2pt - 3
+#length("2pt - 3")
+
+--- length-constructor-infinite eval ---
+// Error: 9-16 invalid length: infpt
+#length("infpt")
+
 --- length-to-absolute paged empty ---
 // Test length `to-absolute` method.
 #set text(size: 12pt)
@@ -21,6 +21,31 @@
   assert(d2.height > 50pt)
 }
 
+--- measure-split paged empty ---
+// Test `measure` across multiple regions.
+#let text = lorem(100)
+
+#context {
+  let regions = measure(width: 200pt, height: 50pt, split: true, text)
+  assert(regions.len() > 1)
+  for size in regions {
+    assert(size.width <= 200pt)
+    assert(size.height <= 50pt)
+  }
+
+  let single = measure(width: 200pt, split: true, text)
+  assert.eq(single.len(), 1)
+  assert(single.first().height > 50pt)
+}
+
+--- measure-split-unbreakable paged empty ---
+// Unbreakable content taller than a region overflows the first one.
+#context {
+  let body = block(width: 50pt, height: 200pt, breakable: false)
+  let regions = measure(width: 50pt, height: 100pt, split: true, body)
+  test(regions, ((width: 50pt, height: 200pt),))
+}
+
 --- measure-counter-width paged ---
 // Measure a counter. Tests that the introspector-assisted location assignment
 // is able to take `here()` from the context into account to find the closest
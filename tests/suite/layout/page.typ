@@ -452,3 +452,90 @@ text
 --- page-bleed-inner-auto eval ---
 // Error: 18-30 expected relative length, found auto
 #set page(bleed: (left: auto))
+
+--- page-justify paged empty ---
+// Spacing in a column broken due to a lack of space is stretched, the last
+// column is left as is. The blocks are invisible so that the test needs no
+// reference output.
+#set page(margin: 0pt, columns: 2, justify: true)
+#set block(spacing: 2pt)
+#let b = block.with(width: 100%, height: 5pt)
+
+#b() <page-justify-a>
+#b() <page-justify-b>
+#b() <page-justify-c>
+#b()
+#b() <page-justify-e>
+
+#context {
+  test(locate(<page-justify-a>).position().y, 0pt)
+  test(locate(<page-justify-b>).position().y, 7.5pt)
+  test(locate(<page-justify-c>).position().y, 15pt)
+  test(locate(<page-justify-e>).position().y, 7pt)
+}
+
+--- page-justify-leading paged empty ---
+// Leading within a paragraph is not stretched.
+#set page(margin: 0pt, columns: 2, justify: true)
+#set block(spacing: 2pt)
+#set par(spacing: 2pt, leading: 1pt)
+#set text(top-edge: 2pt, bottom-edge: 0pt)
+#let b = block.with(width: 100%, height: 5pt)
+#let line = box(height: 2pt)
+
+#line <page-justify-l1> \ #line <page-justify-l2>
+#b()
+#b() <page-justify-last>
+#line <page-justify-l3> \ #line <page-justify-l4>
+
+#context {
+  // The first column ends flush with the bottom.
+  test(locate(<page-justify-last>).position().y, 15pt)
+  // But the lines of the paragraph are as far apart as in the last column.
+  let gap(a, b) = locate(b).position().y - locate(a).position().y
+  test(
+    gap(<page-justify-l1>, <page-justify-l2>),
+    gap(<page-justify-l3>, <page-justify-l4>),
+  )
+}
+
+--- page-justify-colbreak paged empty ---
+// A column ending with an explicit column break is not justified.
+#set page(margin: 0pt, columns: 2, justify: true)
+#set block(spacing: 2pt)
+#let b = block.with(width: 100%, height: 5pt)
+
+#b()
+#b()
+#b() <page-justify-colbreak>
+#colbreak()
+#b()
+
+#context test(locate(<page-justify-colbreak>).position().y, 14pt)
+
+--- page-justify-fractional paged empty ---
+// A column with fractional spacing is not justified.
+#set page(margin: 0pt, columns: 2, justify: true)
+#set block(spacing: 2pt)
+#let b = block.with(width: 100%, height: 5pt)
+
+#b()
+#b() <page-justify-fractional>
+#v(1fr)
+#b()
+#b()
+
+#context test(locate(<page-justify-fractional>).position().y, 7pt)
+
+--- page-justify-too-much-stretch paged empty ---
+// A column that would need more than twice the spacing is not justified.
+#set page(margin: 0pt, columns: 2, justify: true)
+#set block(spacing: 0.5pt)
+#let b = block.with(width: 100%, height: 5pt)
+
+#b()
+#b() <page-justify-too-much>
+#b()
+#b()
+
+#context test(locate(<page-justify-too-much>).position().y, 5.5pt)
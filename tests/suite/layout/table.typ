@@ -326,6 +326,52 @@
 // Hint: 8-19 use `table.cell` instead
 #table(grid.cell[])
 
+--- table-from eval ---
+#let data = ((a: 1, b: "x"), (a: 2.5, c: [y]))
+#let t = table.from(data)
+#test(t.func(), table)
+#test(t.columns, (auto, auto, auto))
+#test(t.align, (right, auto, auto))
+#test(t.children.len(), 7)
+#test(t.children.first().func(), table.header)
+#test(t.children.first().children.map(c => c.body.text), ("a", "b", "c"))
+
+--- table-from-keys-and-format eval ---
+#let t = table.from(
+  ((price: 1, name: "Pear"),),
+  keys: ("price",),
+  header: false,
+  format: (price: p => "$" + str(p)),
+)
+#test(t.columns, (auto,))
+#test(t.children.len(), 1)
+#test(t.children.first().body.text, "$1")
+
+--- table-from-numeric-strings eval ---
+// Strings holding numbers, as loaded from CSV, count as numeric.
+#let data = (
+  (n: "1.5", s: "x", e: ""),
+  (n: " 20", s: "1", e: "3"),
+)
+#test(table.from(data).align, (right, auto, right))
+
+--- table-from-align eval ---
+#let data = ((a: 1, b: "x"),)
+#test(table.from(data, align: center).align, center)
+#test(table.from(((b: "x"),)).has("align"), false)
+
+--- table-from-unknown-format-key eval ---
+// Error: 2-40 format contains unknown column "b"
+#table.from(((a: 1),), format: (b: str))
+
+--- table-from-no-columns eval ---
+// Error: 2-16 table must have at least one column
+#table.from(())
+
+--- table-from-bad-format eval ---
+// Error: 2-39 expected function, found integer
+#table.from(((a: 1),), format: (a: 1))
+
 --- issue-183-table-lines paged ---
 // Ensure no empty lines before a table that doesn't fit into the first page.
 #set page(height: 50pt)
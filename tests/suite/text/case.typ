@@ -18,6 +18,29 @@
 #lower[MY #html.strong[Lower] #symbol("A")] \
 #upper[my #html.strong[Upper] #symbol("a")] \
 
+--- cases-turkic paged empty ---
+// Content follows the Turkic pairing of dotted and dotless i. Since the case is
+// only applied during layout, the results are compared by their glyph bounds,
+// which differ for dotted and dotless letters.
+#set text(top-edge: "bounds", bottom-edge: "bounds")
+#let same(a, b) = context test(measure(a), measure(b))
+
+#context assert.ne(measure[İ], measure[I])
+#context assert.ne(measure[i], measure[ı])
+
+#for lang in ("tr", "az") {
+  set text(lang: lang)
+  same(upper[i], [İ])
+  same(lower[I], [ı])
+  same(lower[İ], [i])
+}
+
+--- cases-turkic-str eval ---
+// Strings have no language and keep the language-independent mapping.
+#set text(lang: "tr")
+#test(upper("istanbul"), "ISTANBUL")
+#test(lower("ISPARTA İZMİR"), "isparta i̇zmi̇r")
+
 --- upper-bad-type eval ---
 // Error: 8-9 expected string or content, found integer
 #upper(1)
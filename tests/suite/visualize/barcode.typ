@@ -0,0 +1,47 @@
+--- barcode-ean-13-width paged empty ---
+// An EAN-13 barcode always consists of 95 modules.
+#context {
+  test(measure(barcode("4006381333931")).width, 95pt)
+  test(measure(barcode("400638133393")).width, 95pt)
+  test(measure(barcode(module: 2pt, "400638133393")).width, 190pt)
+}
+
+--- barcode-code-128-width paged empty ---
+// Start, five data symbols, and checksum take 11 modules each, the stop
+// symbol 13.
+#context test(measure(barcode(kind: "code-128", "Typst")).width, 90pt)
+
+--- barcode-height paged empty ---
+#context test(measure(barcode(height: 1cm, "4006381333931")).height, 1cm)
+
+--- barcode-ean-13-bad-check-digit paged ---
+// Error: 2-26 invalid EAN-13 check digit (expected 1)
+#barcode("4006381333932")
+
+--- barcode-ean-13-bad-length paged ---
+// Error: 2-20 EAN-13 data must consist of 12 or 13 digits, found 7
+#barcode("1234567")
+
+--- barcode-ean-13-non-digit paged ---
+// Error: 2-25 EAN-13 data must only contain digits
+#barcode("40063813339A")
+
+--- barcode-code-128-non-printable paged ---
+// Error: 2-35 Code 128 data must only contain printable ASCII characters
+#barcode(kind: "code-128", "a\tb")
+
+--- barcode-code-128-empty paged ---
+// Error: 2-31 Code 128 data must not be empty
+#barcode(kind: "code-128", "")
+
+--- barcode-zero-module paged ---
+// Error: 2-38 barcode module width must be positive
+#barcode(module: 0pt, "4006381333931")
+
+--- barcode-negative-height paged ---
+// Error: 2-39 barcode height must be positive
+#barcode(height: -1pt, "4006381333931")
+
+--- barcode-bad-kind eval ---
+// Error: 16-20 expected "ean-13" or "code-128"
+#barcode(kind: "qr", "Hello")
@@ -0,0 +1,27 @@
+--- qrcode-size paged empty ---
+// Version 1 has 21 modules per side and holds 14 bytes at level M.
+#context {
+  test(measure(qrcode("Hello")).width, 42pt)
+  test(measure(qrcode(module: 1pt, "Hello")).height, 21pt)
+  test(measure(qrcode(module: 1pt, "a" * 14)).width, 21pt)
+  test(measure(qrcode(module: 1pt, "a" * 15)).width, 25pt)
+}
+
+--- qrcode-level paged empty ---
+// At level H, version 1 only holds 7 bytes.
+#context {
+  test(measure(qrcode(module: 1pt, level: "l", "Hello, world")).width, 21pt)
+  test(measure(qrcode(module: 1pt, level: "h", "Hello, world")).width, 25pt)
+}
+
+--- qrcode-too-long paged ---
+// Error: 2-32 data is too long for a QR code (3000 bytes)
+#qrcode(level: "l", "a" * 3000)
+
+--- qrcode-zero-module paged ---
+// Error: 2-30 QR code module size must be positive
+#qrcode(module: 0pt, "Hello")
+
+--- qrcode-bad-level eval ---
+// Error: 16-19 expected "l", "m", "q", or "h"
+#qrcode(level: "x", "Hello")